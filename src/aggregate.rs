@@ -0,0 +1,317 @@
+use alloc::vec::Vec;
+use core::{
+    fmt::{self, Debug},
+    iter::FromIterator,
+    marker::PhantomData,
+    ops::{Add, Deref, RangeBounds},
+};
+
+use crate::{
+    comparator::{Comparator, OrdComparator},
+    sorted_slice::SortedSlice,
+    sorted_vec::{self, SortedVec},
+    weak_borrow::WeakBorrow,
+};
+
+// `combine` is only ever called with its arguments in slice order, so it does not need to be
+// commutative.
+pub trait Monoid<T> {
+    type Value: Clone;
+
+    fn identity() -> Self::Value;
+    fn lift(item: &T) -> Self::Value;
+    fn combine(x: &Self::Value, y: &Self::Value) -> Self::Value;
+}
+
+pub struct Count;
+
+impl<T> Monoid<T> for Count {
+    type Value = usize;
+
+    fn identity() -> usize {
+        0
+    }
+
+    fn lift(_: &T) -> usize {
+        1
+    }
+
+    fn combine(x: &usize, y: &usize) -> usize {
+        x + y
+    }
+}
+
+pub struct Sum;
+
+impl<T: Clone + Default + Add<Output = T>> Monoid<T> for Sum {
+    type Value = T;
+
+    fn identity() -> T {
+        T::default()
+    }
+
+    fn lift(item: &T) -> T {
+        item.clone()
+    }
+
+    fn combine(x: &T, y: &T) -> T {
+        x.clone() + y.clone()
+    }
+}
+
+pub struct Min;
+
+impl<T: Clone + Ord> Monoid<T> for Min {
+    type Value = Option<T>;
+
+    fn identity() -> Option<T> {
+        None
+    }
+
+    fn lift(item: &T) -> Option<T> {
+        Some(item.clone())
+    }
+
+    fn combine(x: &Option<T>, y: &Option<T>) -> Option<T> {
+        match (x, y) {
+            (Some(x), Some(y)) => Some(Ord::min(x, y).clone()),
+            (x, None) => x.clone(),
+            (None, y) => y.clone(),
+        }
+    }
+}
+
+pub struct Max;
+
+impl<T: Clone + Ord> Monoid<T> for Max {
+    type Value = Option<T>;
+
+    fn identity() -> Option<T> {
+        None
+    }
+
+    fn lift(item: &T) -> Option<T> {
+        Some(item.clone())
+    }
+
+    fn combine(x: &Option<T>, y: &Option<T>) -> Option<T> {
+        match (x, y) {
+            (Some(x), Some(y)) => Some(Ord::max(x, y).clone()),
+            (x, None) => x.clone(),
+            (None, y) => y.clone(),
+        }
+    }
+}
+
+// Every insertion or removal already shifts O(n) items, and shifts the leaves of the tree with
+// them, so mutations rebuild the tree in O(n) rather than patching it.
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct AggregatedVec<T, M: Monoid<T>, C: Comparator<T> = OrdComparator> {
+    _monoid: PhantomData<fn() -> M>,
+    vec: SortedVec<T, C>,
+    // `tree[len..]` holds the lifted items, `tree[i]` combines `tree[2 * i]` and `tree[2 * i + 1]`
+    tree: Vec<M::Value>,
+}
+
+impl<T, M: Monoid<T>, C: Comparator<T>> AggregatedVec<T, M, C> {
+    pub fn new() -> Self {
+        AggregatedVec {
+            _monoid: PhantomData,
+            vec: SortedVec::new(),
+            tree: Vec::new(),
+        }
+    }
+
+    pub fn as_sorted_slice(&self) -> &SortedSlice<T, C> {
+        self.vec.as_sorted_slice()
+    }
+
+    pub fn into_sorted_vec(self) -> SortedVec<T, C> {
+        self.vec
+    }
+
+    pub fn insert(&mut self, item: T) {
+        self.vec.insert(item);
+        self.rebuild();
+    }
+
+    pub fn remove(&mut self, index: usize) -> T {
+        let item = self.vec.remove(index);
+        self.rebuild();
+        item
+    }
+
+    pub fn remove_item<U>(&mut self, item: &U) -> Option<T>
+    where
+        T: WeakBorrow<U>,
+        C: Comparator<U>,
+    {
+        let item = self.vec.remove_item(item)?;
+        self.rebuild();
+        Some(item)
+    }
+
+    pub fn replace(&mut self, index: usize, value: T) -> T {
+        let item = self.vec.replace(index, value);
+        self.rebuild();
+        item
+    }
+
+    pub fn aggregate(&self) -> M::Value {
+        self.aggregate_indices(0, self.vec.len())
+    }
+
+    pub fn aggregate_range<U, R>(&self, range: R) -> M::Value
+    where
+        T: WeakBorrow<U>,
+        C: Comparator<U>,
+        R: RangeBounds<U>,
+    {
+        let (low, high) = self.vec.range_indices(range);
+        self.aggregate_indices(low, high)
+    }
+
+    fn aggregate_indices(&self, low: usize, high: usize) -> M::Value {
+        let len = self.vec.len();
+        let (mut low, mut high) = (low + len, high + len);
+        let mut left = M::identity();
+        let mut right = M::identity();
+
+        while low < high {
+            if low % 2 == 1 {
+                left = M::combine(&left, &self.tree[low]);
+                low += 1;
+            }
+            if high % 2 == 1 {
+                high -= 1;
+                right = M::combine(&self.tree[high], &right);
+            }
+            low /= 2;
+            high /= 2;
+        }
+
+        M::combine(&left, &right)
+    }
+
+    fn rebuild(&mut self) {
+        let len = self.vec.len();
+
+        self.tree.clear();
+        self.tree.resize(len, M::identity());
+        self.tree.extend(self.vec.iter().map(M::lift));
+        for i in (1..len).rev() {
+            self.tree[i] = M::combine(&self.tree[2 * i], &self.tree[2 * i + 1]);
+        }
+    }
+}
+
+impl<T, M: Monoid<T>, C: Comparator<T>> From<SortedVec<T, C>> for AggregatedVec<T, M, C> {
+    fn from(vec: SortedVec<T, C>) -> Self {
+        let mut aggregated = AggregatedVec {
+            _monoid: PhantomData,
+            vec,
+            tree: Vec::new(),
+        };
+        aggregated.rebuild();
+        aggregated
+    }
+}
+
+impl<T: Clone, M: Monoid<T>, C: Comparator<T>> Clone for AggregatedVec<T, M, C> {
+    fn clone(&self) -> Self {
+        AggregatedVec {
+            _monoid: PhantomData,
+            vec: self.vec.clone(),
+            tree: self.tree.clone(),
+        }
+    }
+}
+
+impl<T, M: Monoid<T>, C: Comparator<T>> Default for AggregatedVec<T, M, C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Debug, M: Monoid<T>, C: Comparator<T>> Debug for AggregatedVec<T, M, C> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        Debug::fmt(&self.vec, fmt)
+    }
+}
+
+impl<T, M: Monoid<T>, C: Comparator<T>> Deref for AggregatedVec<T, M, C> {
+    type Target = SortedSlice<T, C>;
+
+    fn deref(&self) -> &SortedSlice<T, C> {
+        self.as_sorted_slice()
+    }
+}
+
+impl<T, M: Monoid<T>, C: Comparator<T>> FromIterator<T> for AggregatedVec<T, M, C> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(sorted_vec::sort_vec(Vec::from_iter(iter)))
+    }
+}
+
+impl<T, M: Monoid<T>, C: Comparator<T>> Extend<T> for AggregatedVec<T, M, C> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.vec.extend(iter);
+        self.rebuild();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Concat;
+
+    impl Monoid<u32> for Concat {
+        type Value = Vec<u32>;
+
+        fn identity() -> Vec<u32> {
+            Vec::new()
+        }
+
+        fn lift(item: &u32) -> Vec<u32> {
+            alloc::vec![*item]
+        }
+
+        fn combine(x: &Vec<u32>, y: &Vec<u32>) -> Vec<u32> {
+            x.iter().chain(y).copied().collect()
+        }
+    }
+
+    #[test]
+    fn test_aggregate_range() {
+        let mut sums: AggregatedVec<u32, Sum> = [5, 1, 9, 3, 7].into_iter().collect();
+        assert_eq!(sums.aggregate(), 25);
+        assert_eq!(sums.aggregate_range(3..=7), 15);
+        assert_eq!(sums.aggregate_range(..5), 4);
+        assert_eq!(sums.aggregate_range(10..), 0);
+
+        sums.insert(4);
+        assert_eq!(sums.aggregate_range(3..=7), 19);
+        sums.remove_item(&5);
+        assert_eq!(sums.aggregate_range(3..=7), 14);
+        sums.replace(0, 6);
+        assert_eq!(sums.as_slice(), &[3, 4, 6, 7, 9]);
+        assert_eq!(sums.aggregate_range(..=6), 13);
+        sums.extend([2, 8]);
+        assert_eq!(sums.aggregate_range(2..9), 30);
+
+        let mut counts: AggregatedVec<u32, Count> = AggregatedVec::new();
+        counts.extend(0..100);
+        assert_eq!(counts.aggregate_range(10..20), 10);
+
+        let mut concat: AggregatedVec<u32, Concat> = (0..13).rev().collect();
+        for low in 0..14 {
+            for high in low..14 {
+                let expected: Vec<u32> = (low..high).collect();
+                assert_eq!(concat.aggregate_range(low..high), expected);
+            }
+        }
+        concat.remove(0);
+        assert_eq!(concat.aggregate(), (1..13).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,116 @@
+use core::cmp::Ordering;
+
+use crate::{
+    comparator::Comparator, sorted_array::SortedArray, sorted_slice::SortedSlice,
+    utils::gallop_partition_point,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BucketBounds {
+    LeftClosed,
+    RightClosed,
+}
+
+impl BucketBounds {
+    fn is_below(self, ordering: Ordering) -> bool {
+        match self {
+            BucketBounds::LeftClosed => ordering != Ordering::Greater,
+            BucketBounds::RightClosed => ordering == Ordering::Less,
+        }
+    }
+}
+
+impl<T, C: Comparator<T>> SortedSlice<T, C> {
+    pub fn bucket_of(&self, item: &T, bounds: BucketBounds) -> usize {
+        self.as_slice()
+            .partition_point(|boundary| bounds.is_below(C::compare(boundary, item)))
+    }
+
+    pub fn bucket_of_many<'a>(
+        &'a self,
+        items: &'a SortedSlice<T, C>,
+        bounds: BucketBounds,
+    ) -> BucketsOf<'a, T, C> {
+        BucketsOf {
+            boundaries: self,
+            items,
+            bounds,
+            bucket: 0,
+        }
+    }
+}
+
+pub struct BucketsOf<'a, T, C: Comparator<T>> {
+    boundaries: &'a SortedSlice<T, C>,
+    items: &'a SortedSlice<T, C>,
+    bounds: BucketBounds,
+    bucket: usize,
+}
+
+impl<T, C: Comparator<T>> Iterator for BucketsOf<'_, T, C> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        let items = self.items;
+        let item = items.first()?;
+        self.items = &items[1..];
+
+        let bounds = self.bounds;
+        self.bucket += gallop_partition_point(&self.boundaries[self.bucket..], |boundary| {
+            bounds.is_below(C::compare(boundary, item))
+        });
+
+        Some(self.bucket)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.items.len(), Some(self.items.len()))
+    }
+}
+
+impl<T, C: Comparator<T>> ExactSizeIterator for BucketsOf<'_, T, C> {}
+
+impl<T, const N: usize, C: Comparator<T>> SortedArray<T, N, C> {
+    pub fn histogram<'a, const M: usize, I>(&self, items: I, bounds: BucketBounds) -> [usize; M]
+    where
+        T: 'a,
+        I: IntoIterator<Item = &'a T>,
+    {
+        const {
+            assert!(
+                M == N + 1,
+                "histogram must have one more bucket than boundaries"
+            )
+        };
+
+        let mut histogram = [0; M];
+        for item in items {
+            histogram[self.bucket_of(item, bounds)] += 1;
+        }
+        histogram
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_bucket_of() {
+        let boundaries = sorted_array![10, 20, 30];
+
+        assert_eq!(boundaries.bucket_of(&5, BucketBounds::LeftClosed), 0);
+        assert_eq!(boundaries.bucket_of(&10, BucketBounds::LeftClosed), 1);
+        assert_eq!(boundaries.bucket_of(&10, BucketBounds::RightClosed), 0);
+        assert_eq!(boundaries.bucket_of(&35, BucketBounds::RightClosed), 3);
+
+        let items = sorted_array![1, 10, 15, 20, 31, 40];
+        let mut buckets = boundaries.bucket_of_many(&items, BucketBounds::RightClosed);
+        assert_eq!(buckets.len(), 6);
+        assert!(buckets.by_ref().eq([0, 0, 1, 1, 3, 3]));
+
+        let histogram: [usize; 4] =
+            boundaries.histogram(&[1, 10, 15, 20, 31, 40], BucketBounds::LeftClosed);
+        assert_eq!(histogram, [1, 2, 1, 2]);
+    }
+}
@@ -0,0 +1,165 @@
+use core::{
+    fmt::{self, Display},
+    marker::PhantomData,
+};
+use std::{format, io, string::ToString, vec::Vec};
+
+use crate::comparator::{Comparator, OrdComparator};
+
+pub struct StaticSortedArray<'a, C = OrdComparator> {
+    _comparator: PhantomData<fn() -> C>,
+    name: &'a str,
+    item_type: &'a str,
+    comparator: Option<&'a str>,
+    visibility: &'a str,
+    crate_path: &'a str,
+}
+
+impl<'a> StaticSortedArray<'a> {
+    pub fn new(name: &'a str, item_type: &'a str) -> Self {
+        StaticSortedArray {
+            _comparator: PhantomData,
+            name,
+            item_type,
+            comparator: None,
+            visibility: "pub",
+            crate_path: "::sorted_array",
+        }
+    }
+}
+
+impl<'a, C> StaticSortedArray<'a, C> {
+    pub fn comparator<D>(self, comparator: &'a str) -> StaticSortedArray<'a, D> {
+        StaticSortedArray {
+            _comparator: PhantomData,
+            name: self.name,
+            item_type: self.item_type,
+            comparator: Some(comparator),
+            visibility: self.visibility,
+            crate_path: self.crate_path,
+        }
+    }
+
+    pub fn visibility(mut self, visibility: &'a str) -> Self {
+        self.visibility = visibility;
+        self
+    }
+
+    pub fn crate_path(mut self, crate_path: &'a str) -> Self {
+        self.crate_path = crate_path;
+        self
+    }
+
+    pub fn write<T, I, F, W>(&self, out: &mut W, items: I, format: F) -> io::Result<()>
+    where
+        C: Comparator<T>,
+        I: IntoIterator<Item = T>,
+        F: Fn(&T, &mut fmt::Formatter) -> fmt::Result,
+        W: io::Write + ?Sized,
+    {
+        let mut items: Vec<T> = items.into_iter().collect();
+        items.sort_by(C::compare);
+        items.dedup_by(|a, b| C::compare(a, b).is_eq());
+
+        let StaticSortedArray {
+            _comparator,
+            name,
+            item_type,
+            comparator,
+            visibility,
+            crate_path,
+        } = *self;
+        let len = items.len();
+        let comparator = match comparator {
+            Some(comparator) => format!(", {comparator}"),
+            None => "".to_string(),
+        };
+
+        writeln!(
+            out,
+            "{visibility} static {name}: {crate_path}::SortedArray<{item_type}, {len}{comparator}> = unsafe {{"
+        )?;
+        writeln!(out, "    {crate_path}::SortedArray::from_array_unchecked([")?;
+        for item in &items {
+            writeln!(out, "        {},", DisplayWith(item, &format))?;
+        }
+        writeln!(out, "    ])")?;
+        writeln!(out, "}};")?;
+
+        Ok(())
+    }
+}
+
+struct DisplayWith<'a, T, F>(&'a T, &'a F);
+
+impl<T, F> Display for DisplayWith<'_, T, F>
+where
+    F: Fn(&T, &mut fmt::Formatter) -> fmt::Result,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        (self.1)(self.0, fmt)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::{cmp::Ordering, fmt::Debug};
+    use std::string::String;
+
+    use super::*;
+
+    #[test]
+    fn test_write() {
+        let mut out = Vec::new();
+        StaticSortedArray::new("CODES", "(u16, &str)")
+            .write(
+                &mut out,
+                [(404, "Not Found"), (200, "OK"), (404, "Not Found")],
+                Debug::fmt,
+            )
+            .unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "pub static CODES: ::sorted_array::SortedArray<(u16, &str), 2> = unsafe {\n\
+             \x20   ::sorted_array::SortedArray::from_array_unchecked([\n\
+             \x20       (200, \"OK\"),\n\
+             \x20       (404, \"Not Found\"),\n\
+             \x20   ])\n\
+             };\n"
+        );
+    }
+
+    #[test]
+    fn test_write_with_options() {
+        struct ByCode;
+
+        impl Comparator<(u16, &str)> for ByCode {
+            fn compare(x: &(u16, &str), y: &(u16, &str)) -> Ordering {
+                Ord::cmp(&x.0, &y.0)
+            }
+        }
+
+        let mut out = Vec::new();
+        StaticSortedArray::new("CODES", "(u16, &str)")
+            .comparator::<ByCode>("crate::ByCode")
+            .visibility("pub(crate)")
+            .crate_path("sorted")
+            .write(
+                &mut out,
+                [(404, "Not Found"), (200, "OK"), (404, "Missing")],
+                Debug::fmt,
+            )
+            .unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "pub(crate) static CODES: sorted::SortedArray<(u16, &str), 2, crate::ByCode> = unsafe {\n\
+             \x20   sorted::SortedArray::from_array_unchecked([\n\
+             \x20       (200, \"OK\"),\n\
+             \x20       (404, \"Not Found\"),\n\
+             \x20   ])\n\
+             };\n"
+        );
+    }
+}
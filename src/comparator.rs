@@ -1,4 +1,4 @@
-use core::cmp::Ordering;
+use core::{cmp::Ordering, marker::PhantomData};
 
 pub trait Comparator<T: ?Sized> {
     fn compare(x: &T, y: &T) -> Ordering;
@@ -11,3 +11,11 @@ impl<T: Ord + ?Sized> Comparator<T> for OrdComparator {
         Ord::cmp(x, y)
     }
 }
+
+pub struct RefComparator<C>(PhantomData<fn() -> C>);
+
+impl<'a, T: ?Sized, C: Comparator<T>> Comparator<&'a T> for RefComparator<C> {
+    fn compare(x: &&'a T, y: &&'a T) -> Ordering {
+        C::compare(*x, *y)
+    }
+}
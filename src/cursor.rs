@@ -0,0 +1,237 @@
+use core::{
+    cmp::Ordering,
+    fmt::{self, Debug, Display},
+};
+
+#[cfg(feature = "alloc")]
+use crate::sorted_vec::{self, SortedVec};
+use crate::{comparator::Comparator, sorted_slice::SortedSlice, weak_borrow::WeakBorrow};
+
+impl<T, C: Comparator<T>> SortedSlice<T, C> {
+    pub fn cursor_at<U>(&self, key: &U) -> Cursor<'_, T, C>
+    where
+        T: WeakBorrow<U>,
+        C: Comparator<U>,
+    {
+        Cursor {
+            index: lower_bound(self, key),
+            slice: self,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, C: Comparator<T>> SortedVec<T, C> {
+    pub fn cursor_at_mut<U>(&mut self, key: &U) -> CursorMut<'_, T, C>
+    where
+        T: WeakBorrow<U>,
+        C: Comparator<U>,
+    {
+        CursorMut {
+            index: lower_bound(self, key),
+            vec: self,
+        }
+    }
+}
+
+fn lower_bound<T, U, C>(slice: &SortedSlice<T, C>, key: &U) -> usize
+where
+    T: WeakBorrow<U>,
+    C: Comparator<T> + Comparator<U>,
+{
+    slice.partition_point(|it| C::compare(it.weak_borrow(), key) == Ordering::Less)
+}
+
+pub struct Cursor<'a, T, C: Comparator<T>> {
+    slice: &'a SortedSlice<T, C>,
+    index: usize,
+}
+
+impl<'a, T, C: Comparator<T>> Cursor<'a, T, C> {
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn peek_prev(&self) -> Option<&'a T> {
+        self.index.checked_sub(1).map(|index| &self.slice[index])
+    }
+
+    pub fn peek_next(&self) -> Option<&'a T> {
+        self.slice.get(self.index)
+    }
+
+    pub fn move_prev(&mut self) -> Option<&'a T> {
+        let item = self.peek_prev()?;
+        self.index -= 1;
+        Some(item)
+    }
+
+    pub fn move_next(&mut self) -> Option<&'a T> {
+        let item = self.peek_next()?;
+        self.index += 1;
+        Some(item)
+    }
+}
+
+impl<T, C: Comparator<T>> Clone for Cursor<'_, T, C> {
+    fn clone(&self) -> Self {
+        Cursor {
+            slice: self.slice,
+            index: self.index,
+        }
+    }
+}
+
+impl<T: Debug, C: Comparator<T>> Debug for Cursor<'_, T, C> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_tuple("Cursor")
+            .field(&self.peek_prev())
+            .field(&self.peek_next())
+            .finish()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnorderedKeyError<T>(pub T);
+
+impl<T> Display for UnorderedKeyError<T> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("item is not ordered relative to the items around the cursor")
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Debug> std::error::Error for UnorderedKeyError<T> {}
+
+// Inserted items are only compared against their two neighbours rather than searched for.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct CursorMut<'a, T, C: Comparator<T>> {
+    vec: &'a mut SortedVec<T, C>,
+    index: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl<T, C: Comparator<T>> CursorMut<'_, T, C> {
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn as_cursor(&self) -> Cursor<'_, T, C> {
+        Cursor {
+            slice: self.vec.as_sorted_slice(),
+            index: self.index,
+        }
+    }
+
+    pub fn peek_prev(&self) -> Option<&T> {
+        self.index.checked_sub(1).map(|index| &self.vec[index])
+    }
+
+    pub fn peek_next(&self) -> Option<&T> {
+        self.vec.get(self.index)
+    }
+
+    pub fn move_prev(&mut self) -> Option<&T> {
+        self.index = self.index.checked_sub(1)?;
+        Some(&self.vec[self.index])
+    }
+
+    pub fn move_next(&mut self) -> Option<&T> {
+        let item = self.vec.get(self.index)?;
+        self.index += 1;
+        Some(item)
+    }
+
+    pub fn insert_before(&mut self, item: T) -> Result<(), UnorderedKeyError<T>> {
+        self.insert(item)?;
+        self.index += 1;
+        Ok(())
+    }
+
+    pub fn insert_after(&mut self, item: T) -> Result<(), UnorderedKeyError<T>> {
+        self.insert(item)
+    }
+
+    // removes the item after the cursor, the one `peek_next` returns
+    pub fn remove_current(&mut self) -> Option<T> {
+        if self.index < self.vec.len() {
+            Some(self.vec.remove(self.index))
+        } else {
+            None
+        }
+    }
+
+    fn insert(&mut self, item: T) -> Result<(), UnorderedKeyError<T>> {
+        let after_prev = self
+            .peek_prev()
+            .is_none_or(|prev| C::compare(prev, &item) != Ordering::Greater);
+        let before_next = self
+            .peek_next()
+            .is_none_or(|next| C::compare(&item, next) != Ordering::Greater);
+        if !(after_prev && before_next) {
+            return Err(UnorderedKeyError(item));
+        }
+
+        sorted_vec::as_mut_vec_unchecked(self.vec).insert(self.index, item);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Debug, C: Comparator<T>> Debug for CursorMut<'_, T, C> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_tuple("CursorMut")
+            .field(&self.peek_prev())
+            .field(&self.peek_next())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::sorted_array::SortedArray;
+
+    #[test]
+    fn test_cursor() {
+        let array = SortedArray::<i32, 4>::from_sorted([1, 3, 3, 5]);
+
+        let mut cursor = array.cursor_at(&3);
+        assert_eq!(cursor.index(), 1);
+        assert_eq!(cursor.peek_prev(), Some(&1));
+        assert_eq!(cursor.move_next(), Some(&3));
+        assert_eq!(cursor.move_next(), Some(&3));
+        assert_eq!(cursor.move_next(), Some(&5));
+        assert_eq!(cursor.move_next(), None);
+        assert_eq!(cursor.index(), 4);
+
+        let mut cursor = array.cursor_at(&0);
+        assert_eq!(cursor.move_prev(), None);
+        assert_eq!(cursor.peek_next(), Some(&1));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_cursor_mut() {
+        use crate::cursor::UnorderedKeyError;
+
+        let mut vec = sorted_vec![10, 20, 30];
+
+        let mut cursor = vec.cursor_at_mut(&20);
+        assert_eq!(cursor.insert_before(15), Ok(()));
+        assert_eq!(cursor.insert_before(17), Ok(()));
+        assert_eq!(cursor.insert_before(16), Err(UnorderedKeyError(16)));
+        assert_eq!(cursor.insert_after(25), Err(UnorderedKeyError(25)));
+        assert_eq!(cursor.insert_after(20), Ok(()));
+        assert_eq!(cursor.remove_current(), Some(20));
+        assert_eq!(cursor.peek_next(), Some(&20));
+
+        let mut cursor = vec.cursor_at_mut(&40);
+        assert_eq!(cursor.insert_before(40), Ok(()));
+        assert_eq!(cursor.insert_before(50), Ok(()));
+        assert_eq!(cursor.remove_current(), None);
+        assert_eq!(cursor.move_prev(), Some(&50));
+
+        assert_eq!(vec.as_slice(), &[10, 15, 17, 20, 30, 40, 50]);
+    }
+}
@@ -0,0 +1,121 @@
+use core::cmp::Ordering;
+
+use crate::{comparator::Comparator, sorted_slice::SortedSlice};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DiffItem<'a, T> {
+    Added(&'a T),
+    Removed(&'a T),
+    Changed(&'a T, &'a T),
+}
+
+impl<T: PartialEq, C: Comparator<T>> SortedSlice<T, C> {
+    pub fn diff<'a>(&'a self, other: &'a SortedSlice<T, C>) -> Diff<'a, T, C> {
+        Diff {
+            old: self,
+            new: other,
+        }
+    }
+}
+
+pub struct Diff<'a, T, C: Comparator<T>> {
+    old: &'a SortedSlice<T, C>,
+    new: &'a SortedSlice<T, C>,
+}
+
+impl<'a, T: PartialEq, C: Comparator<T>> Diff<'a, T, C> {
+    // Skips the common prefix of identical items, comparing chunks of doubling size so that long
+    // unchanged stretches are skipped with few (and vectorizable) slice comparisons.
+    fn skip_identical(&mut self) {
+        let (old, new) = (self.old.as_slice(), self.new.as_slice());
+        let len = old.len().min(new.len());
+
+        let mut index = 0;
+        let mut step = 1;
+        while index + step <= len && old[index..index + step] == new[index..index + step] {
+            index += step;
+            step *= 2;
+        }
+        while index < len && old[index] == new[index] {
+            index += 1;
+        }
+
+        self.old = &self.old[index..];
+        self.new = &self.new[index..];
+    }
+}
+
+impl<'a, T: PartialEq, C: Comparator<T>> Iterator for Diff<'a, T, C> {
+    type Item = DiffItem<'a, T>;
+
+    fn next(&mut self) -> Option<DiffItem<'a, T>> {
+        loop {
+            self.skip_identical();
+
+            let (old, new) = (self.old, self.new);
+            return match (old.first(), new.first()) {
+                (None, None) => None,
+                (Some(removed), None) => {
+                    self.old = &old[1..];
+                    Some(DiffItem::Removed(removed))
+                }
+                (None, Some(added)) => {
+                    self.new = &new[1..];
+                    Some(DiffItem::Added(added))
+                }
+                (Some(x), Some(y)) => match C::compare(x, y) {
+                    Ordering::Less => {
+                        self.old = &old[1..];
+                        Some(DiffItem::Removed(x))
+                    }
+                    Ordering::Greater => {
+                        self.new = &new[1..];
+                        Some(DiffItem::Added(y))
+                    }
+                    Ordering::Equal => {
+                        self.old = &old[1..];
+                        self.new = &new[1..];
+                        if x == y {
+                            continue;
+                        }
+                        Some(DiffItem::Changed(x, y))
+                    }
+                },
+            };
+        }
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod test {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    struct ByKey;
+
+    impl Comparator<(char, u32)> for ByKey {
+        fn compare(x: &(char, u32), y: &(char, u32)) -> Ordering {
+            Ord::cmp(&x.0, &y.0)
+        }
+    }
+
+    #[test]
+    fn test_diff() {
+        let old = sorted_vec![@ByKey; ('a', 1), ('b', 2), ('c', 3), ('d', 4), ('f', 6)];
+        let new = sorted_vec![@ByKey; ('a', 1), ('b', 2), ('c', 30), ('e', 5), ('f', 6), ('g', 7)];
+
+        let diff: Vec<_> = old.diff(&new).collect();
+        assert_eq!(
+            diff,
+            [
+                DiffItem::Changed(&('c', 3), &('c', 30)),
+                DiffItem::Removed(&('d', 4)),
+                DiffItem::Added(&('e', 5)),
+                DiffItem::Added(&('g', 7)),
+            ]
+        );
+
+        assert_eq!(new.diff(&new).count(), 0);
+    }
+}
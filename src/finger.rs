@@ -0,0 +1,145 @@
+use core::{
+    cmp::Ordering,
+    ops::{Bound, RangeBounds},
+};
+
+use crate::{
+    comparator::Comparator,
+    sorted_slice::SortedSlice,
+    utils::{gallop_partition_point, gallop_partition_point_rev},
+    weak_borrow::WeakBorrow,
+};
+
+impl<T, C: Comparator<T>> SortedSlice<T, C> {
+    pub fn finger(&self) -> Finger<'_, T, C> {
+        Finger {
+            slice: self,
+            index: 0,
+        }
+    }
+}
+
+// Each lookup gallops outwards from the position of the previous one, so it costs O(log d) for a
+// distance d. Once a gallop overshoots the end of the slice the rest of the search is a plain
+// binary search, so a large jump costs at most about twice as much as a fresh lookup.
+pub struct Finger<'a, T, C: Comparator<T>> {
+    slice: &'a SortedSlice<T, C>,
+    index: usize,
+}
+
+impl<'a, T, C: Comparator<T>> Finger<'a, T, C> {
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn find<U>(&mut self, item: &U) -> Option<&'a T>
+    where
+        T: WeakBorrow<U>,
+        C: Comparator<U>,
+    {
+        let index = self.seek(|it| C::compare(it.weak_borrow(), item) == Ordering::Less);
+        self.slice
+            .get(index)
+            .filter(|it| C::compare((*it).weak_borrow(), item) == Ordering::Equal)
+    }
+
+    pub fn floor<U>(&mut self, item: &U) -> Option<&'a T>
+    where
+        T: WeakBorrow<U>,
+        C: Comparator<U>,
+    {
+        let index = self.seek(|it| C::compare(it.weak_borrow(), item) != Ordering::Greater);
+        index.checked_sub(1).map(|index| &self.slice[index])
+    }
+
+    pub fn range<U, R>(&mut self, range: R) -> &'a SortedSlice<T, C>
+    where
+        T: WeakBorrow<U>,
+        C: Comparator<U>,
+        R: RangeBounds<U>,
+    {
+        let low = match range.start_bound() {
+            Bound::Included(start) => {
+                self.seek(|it| C::compare(it.weak_borrow(), start) == Ordering::Less)
+            }
+            Bound::Excluded(start) => {
+                self.seek(|it| C::compare(it.weak_borrow(), start) != Ordering::Greater)
+            }
+            Bound::Unbounded => {
+                self.index = 0;
+                0
+            }
+        };
+
+        // the end is found from the start without moving the finger, so that the next range
+        // starting near this one is found from here
+        let rest = &self.slice[low..];
+        let high = low
+            + match range.end_bound() {
+                Bound::Included(end) => gallop_partition_point(rest, |it| {
+                    C::compare(it.weak_borrow(), end) != Ordering::Greater
+                }),
+                Bound::Excluded(end) => gallop_partition_point(rest, |it| {
+                    C::compare(it.weak_borrow(), end) == Ordering::Less
+                }),
+                Bound::Unbounded => rest.len(),
+            };
+
+        &self.slice[low..high]
+    }
+
+    fn seek<P>(&mut self, mut pred: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let slice = self.slice.as_slice();
+        let index = self.index;
+
+        self.index = if index < slice.len() && pred(&slice[index]) {
+            index + 1 + gallop_partition_point(&slice[index + 1..], pred)
+        } else {
+            gallop_partition_point_rev(&slice[..index], pred)
+        };
+        self.index
+    }
+}
+
+impl<T, C: Comparator<T>> Clone for Finger<'_, T, C> {
+    fn clone(&self) -> Self {
+        Finger {
+            slice: self.slice,
+            index: self.index,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::sorted_array::SortedArray;
+
+    #[test]
+    fn test_finger() {
+        let array = SortedArray::<i32, 12>::from_sorted([1, 2, 3, 3, 3, 4, 4, 6, 7, 8, 9, 10]);
+        let mut finger = array.finger();
+
+        for item in [0, 3, 4, 5, 11, 9, 1, 6, 6, 3, -1, 10] {
+            assert_eq!(finger.find(&item), array.find(&item));
+            assert_eq!(
+                finger.floor(&item),
+                array.iter().rev().find(|it| **it <= item)
+            );
+            assert_eq!(
+                finger.range(item..item + 3).as_slice(),
+                array.range(item..item + 3).as_slice()
+            );
+            assert_eq!(
+                finger.range(item..).as_slice(),
+                array.range(item..).as_slice()
+            );
+            assert_eq!(
+                finger.range(..=item).as_slice(),
+                array.range(..=item).as_slice()
+            );
+        }
+    }
+}
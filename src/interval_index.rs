@@ -0,0 +1,268 @@
+use alloc::vec::Vec;
+use core::{
+    cmp::Ordering,
+    ops::{Bound, Range},
+};
+
+use crate::{
+    comparator::{Comparator, OrdComparator},
+    interval_set::StartComparator,
+    sorted_slice::SortedSlice,
+    sorted_vec::{self, sort_vec, SortedVec},
+    utils::{lt, max},
+};
+
+// Intervals are stored sorted by start, and additionally form an implicit binary search tree
+// where the node at index `i` has level `i.trailing_ones()`. Each node is augmented with the
+// maximum end of its subtree, so that subtrees which end before the query can be skipped.
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct IntervalIndex<T, C: Comparator<T> = OrdComparator> {
+    intervals: SortedVec<Range<T>, StartComparator<C>>,
+    max_ends: Vec<T>,
+    ends: Vec<T>,
+    root_level: u32,
+}
+
+impl<T: Clone, C: Comparator<T>> IntervalIndex<T, C> {
+    pub fn new(mut intervals: SortedVec<Range<T>, StartComparator<C>>) -> Self {
+        // empty intervals never contain or overlap anything, and the counts rely on every
+        // interval ending after it starts
+        sorted_vec::as_mut_vec_unchecked(&mut intervals)
+            .retain(|it| lt::<T, C>(&it.start, &it.end));
+
+        let slice = intervals.as_slice();
+        let len = slice.len();
+
+        let mut max_ends: Vec<T> = slice.iter().map(|it| it.end.clone()).collect();
+        let mut ends = max_ends.clone();
+        ends.sort_by(C::compare);
+
+        let mut root_level = 0;
+        if len > 0 {
+            let mut last_index = (len - 1) & !1;
+            let mut last = slice[last_index].end.clone();
+
+            let mut level = 1;
+            while 1 << level <= len {
+                let half = 1 << (level - 1);
+                for index in ((half << 1) - 1..len).step_by(half << 2) {
+                    let mut max_end = &slice[index].end;
+                    max_end = max::<T, C>(max_end, &max_ends[index - half]);
+                    max_end = max::<T, C>(max_end, max_ends.get(index + half).unwrap_or(&last));
+                    max_ends[index] = max_end.clone();
+                }
+
+                last_index = if last_index >> level & 1 == 1 {
+                    last_index - half
+                } else {
+                    last_index + half
+                };
+                if last_index < len && lt::<T, C>(&last, &max_ends[last_index]) {
+                    last = max_ends[last_index].clone();
+                }
+
+                level += 1;
+            }
+            root_level = level - 1;
+        }
+
+        IntervalIndex {
+            intervals,
+            max_ends,
+            ends,
+            root_level,
+        }
+    }
+
+    pub fn as_sorted_slice(&self) -> &SortedSlice<Range<T>, StartComparator<C>> {
+        self.intervals.as_sorted_slice()
+    }
+
+    pub fn len(&self) -> usize {
+        self.intervals.as_slice().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.as_slice().is_empty()
+    }
+
+    pub fn containing(&self, point: &T) -> Overlapping<'_, T, C> {
+        self.query(point.clone(), Bound::Included(point.clone()))
+    }
+
+    pub fn overlapping(&self, range: Range<T>) -> Overlapping<'_, T, C> {
+        self.query(range.start, Bound::Excluded(range.end))
+    }
+
+    pub fn count_containing(&self, point: &T) -> usize {
+        let starts = self
+            .intervals
+            .as_slice()
+            .partition_point(|it| !lt::<T, C>(point, &it.start));
+        let ends = self.ends.partition_point(|it| !lt::<T, C>(point, it));
+        starts - ends
+    }
+
+    pub fn count_overlapping(&self, range: Range<T>) -> usize {
+        if !lt::<T, C>(&range.start, &range.end) {
+            return 0;
+        }
+
+        let starts = self
+            .intervals
+            .as_slice()
+            .partition_point(|it| lt::<T, C>(&it.start, &range.end));
+        let ends = self
+            .ends
+            .partition_point(|it| !lt::<T, C>(&range.start, it));
+        starts - ends
+    }
+
+    fn query(&self, start: T, end: Bound<T>) -> Overlapping<'_, T, C> {
+        let mut overlapping = Overlapping {
+            index: self,
+            start,
+            end,
+            stack: [(0, 0, false); 64],
+            stack_len: 0,
+            scan: 0..0,
+        };
+
+        let empty = match &overlapping.end {
+            Bound::Excluded(end) => !lt::<T, C>(&overlapping.start, end),
+            _ => false,
+        };
+        if !self.is_empty() && !empty {
+            overlapping.push((1 << self.root_level) - 1, self.root_level, false);
+        }
+
+        overlapping
+    }
+}
+
+pub struct Overlapping<'a, T, C: Comparator<T>> {
+    index: &'a IntervalIndex<T, C>,
+    start: T,
+    end: Bound<T>,
+    stack: [(usize, u32, bool); 64],
+    stack_len: usize,
+    scan: Range<usize>,
+}
+
+impl<T, C: Comparator<T>> Overlapping<'_, T, C> {
+    fn push(&mut self, index: usize, level: u32, visited_left: bool) {
+        self.stack[self.stack_len] = (index, level, visited_left);
+        self.stack_len += 1;
+    }
+
+    fn starts_before_end(&self, interval: &Range<T>) -> bool {
+        match &self.end {
+            Bound::Included(end) => C::compare(&interval.start, end) != Ordering::Greater,
+            Bound::Excluded(end) => lt::<T, C>(&interval.start, end),
+            Bound::Unbounded => true,
+        }
+    }
+}
+
+impl<'a, T, C: Comparator<T>> Iterator for Overlapping<'a, T, C> {
+    type Item = &'a Range<T>;
+
+    fn next(&mut self) -> Option<&'a Range<T>> {
+        let intervals = self.index.intervals.as_slice();
+        let len = intervals.len();
+
+        loop {
+            if let Some(index) = self.scan.next() {
+                let interval = &intervals[index];
+                if !self.starts_before_end(interval) {
+                    self.scan = 0..0;
+                } else if lt::<T, C>(&self.start, &interval.end) {
+                    return Some(interval);
+                }
+                continue;
+            }
+
+            if self.stack_len == 0 {
+                return None;
+            }
+            self.stack_len -= 1;
+            let (index, level, visited_left) = self.stack[self.stack_len];
+
+            if level <= 3 {
+                // small subtrees are scanned linearly
+                let low = index >> level << level;
+                let high = (low + (1 << (level + 1)) - 1).min(len);
+                self.scan = low..high;
+            } else if !visited_left {
+                let left = index - (1 << (level - 1));
+                self.push(index, level, true);
+                if left >= len || lt::<T, C>(&self.start, &self.index.max_ends[left]) {
+                    self.push(left, level - 1, false);
+                }
+            } else if index < len && self.starts_before_end(&intervals[index]) {
+                self.push(index + (1 << (level - 1)), level - 1, false);
+                if lt::<T, C>(&self.start, &intervals[index].end) {
+                    return Some(&intervals[index]);
+                }
+            }
+        }
+    }
+}
+
+impl<T: Clone, C: Comparator<T>> FromIterator<Range<T>> for IntervalIndex<T, C> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        IntervalIndex::new(sort_vec(iter.into_iter().collect()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_queries() {
+        let mut seed = 12345u32;
+        let mut random = move |n: u32| {
+            seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+            (seed >> 16) % n
+        };
+
+        let intervals: Vec<Range<u32>> = (0..300)
+            .map(|_| {
+                let start = random(1000);
+                let max_len = if random(10) == 0 { 500 } else { 20 };
+                start..start + 1 + random(max_len)
+            })
+            .collect();
+        let index: IntervalIndex<u32> = intervals.iter().cloned().collect();
+
+        for _ in 0..200 {
+            let point = random(1100);
+            let expected = intervals.iter().filter(|it| it.contains(&point)).count();
+            assert_eq!(index.containing(&point).count(), expected);
+            assert_eq!(index.count_containing(&point), expected);
+            assert!(index.containing(&point).all(|it| it.contains(&point)));
+
+            let range = point..point + 1 + random(50);
+            let expected = intervals
+                .iter()
+                .filter(|it| it.start < range.end && range.start < it.end)
+                .count();
+            assert_eq!(index.overlapping(range.clone()).count(), expected);
+            assert_eq!(index.count_overlapping(range), expected);
+        }
+    }
+
+    #[test]
+    fn test_empty_intervals() {
+        #[allow(clippy::reversed_empty_ranges)]
+        let index: IntervalIndex<u32> = [0..100, 50..10, 20..20].into_iter().collect();
+        assert_eq!(index.len(), 1);
+
+        assert_eq!(index.containing(&20).count(), 1);
+        assert_eq!(index.count_containing(&20), 1);
+        assert_eq!(index.count_containing(&30), 1);
+        assert_eq!(index.overlapping(20..30).count(), 1);
+        assert_eq!(index.count_overlapping(20..30), 1);
+    }
+}
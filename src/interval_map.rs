@@ -0,0 +1,222 @@
+use alloc::vec::Vec;
+use core::{
+    cmp::Ordering,
+    fmt::{self, Debug, Display},
+    ops::Range,
+};
+
+use crate::{
+    comparator::{Comparator, OrdComparator},
+    interval_set::StartComparator,
+    sorted_vec::{self, SortedVec},
+    utils::lt,
+};
+
+impl<T, V, C: Comparator<T>> Comparator<(Range<T>, V)> for StartComparator<C> {
+    fn compare(x: &(Range<T>, V), y: &(Range<T>, V)) -> Ordering {
+        C::compare(&x.0.start, &y.0.start)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IntervalMapError {
+    EmptyInterval { index: usize },
+    OverlappingInterval { index: usize },
+}
+
+impl Display for IntervalMapError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IntervalMapError::EmptyInterval { index } => {
+                write!(fmt, "interval at index {index} is empty")
+            }
+            IntervalMapError::OverlappingInterval { index } => write!(
+                fmt,
+                "interval at index {index} overlaps or is ordered before the previous interval"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for IntervalMapError {}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct IntervalMap<K, V, C: Comparator<K> = OrdComparator> {
+    entries: SortedVec<(Range<K>, V), StartComparator<C>>,
+}
+
+impl<K, V, C: Comparator<K>> IntervalMap<K, V, C> {
+    pub fn new() -> Self {
+        IntervalMap {
+            entries: SortedVec::new(),
+        }
+    }
+
+    pub fn from_sorted<I>(entries: I) -> Result<Self, IntervalMapError>
+    where
+        I: IntoIterator<Item = (Range<K>, V)>,
+    {
+        let entries: Vec<(Range<K>, V)> = entries.into_iter().collect();
+
+        for (index, (range, _)) in entries.iter().enumerate() {
+            if !lt::<K, C>(&range.start, &range.end) {
+                return Err(IntervalMapError::EmptyInterval { index });
+            }
+            if index > 0 && lt::<K, C>(&range.start, &entries[index - 1].0.end) {
+                return Err(IntervalMapError::OverlappingInterval { index });
+            }
+        }
+
+        Ok(IntervalMap {
+            entries: sorted_vec::from_vec_unchecked(entries),
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.as_slice().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.as_slice().is_empty()
+    }
+
+    pub fn iter(&self) -> core::slice::Iter<'_, (Range<K>, V)> {
+        self.entries.as_slice().iter()
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.get_key_value(key).map(|(_, value)| value)
+    }
+
+    pub fn get_key_value(&self, key: &K) -> Option<(&Range<K>, &V)> {
+        let entries = self.entries.as_slice();
+        let index = entries.partition_point(|(range, _)| !lt::<K, C>(key, &range.start));
+
+        let (range, value) = entries[..index].last()?;
+        lt::<K, C>(key, &range.end).then_some((range, value))
+    }
+
+    pub fn range(&self, range: Range<K>) -> core::slice::Iter<'_, (Range<K>, V)> {
+        let (low, high) = self.overlapping(&range);
+        self.entries.as_slice()[low..high].iter()
+    }
+
+    fn overlapping(&self, range: &Range<K>) -> (usize, usize) {
+        let entries = self.entries.as_slice();
+        let low = entries.partition_point(|(it, _)| !lt::<K, C>(&range.start, &it.end));
+        let high = entries.partition_point(|(it, _)| lt::<K, C>(&it.start, &range.end));
+        (low, high.max(low))
+    }
+}
+
+impl<K: Clone, V: Clone, C: Comparator<K>> IntervalMap<K, V, C> {
+    pub fn insert(&mut self, range: Range<K>, value: V) {
+        if !lt::<K, C>(&range.start, &range.end) {
+            return;
+        }
+
+        let (low, high) = self.overlapping(&range);
+        let entries = sorted_vec::as_mut_vec_unchecked(&mut self.entries);
+        let removed: Vec<_> = entries.drain(low..high).collect();
+
+        let mut replacement = Vec::with_capacity(3);
+        if let Some((first, first_value)) = removed.first() {
+            if lt::<K, C>(&first.start, &range.start) {
+                replacement.push((
+                    first.start.clone()..range.start.clone(),
+                    first_value.clone(),
+                ));
+            }
+        }
+        let end = range.end.clone();
+        replacement.push((range, value));
+        if let Some((last, last_value)) = removed.last() {
+            if lt::<K, C>(&end, &last.end) {
+                replacement.push((end..last.end.clone(), last_value.clone()));
+            }
+        }
+
+        entries.splice(low..low, replacement);
+    }
+}
+
+impl<K: Clone, V: Clone, C: Comparator<K>> Clone for IntervalMap<K, V, C> {
+    fn clone(&self) -> Self {
+        IntervalMap {
+            entries: self.entries.clone(),
+        }
+    }
+}
+
+impl<K, V, C: Comparator<K>> Default for IntervalMap<K, V, C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Debug, V: Debug, C: Comparator<K>> Debug for IntervalMap<K, V, C> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_map()
+            .entries(
+                self.entries
+                    .as_slice()
+                    .iter()
+                    .map(|(range, value)| (range, value)),
+            )
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::vec;
+
+    use super::*;
+
+    #[test]
+    fn test_insert_get() {
+        let mut map: IntervalMap<u32, char> = IntervalMap::new();
+        map.insert(0..10, 'a');
+        map.insert(20..30, 'b');
+        map.insert(5..25, 'c');
+        map.insert(7..8, 'd');
+
+        assert_eq!(
+            map.iter().cloned().collect::<Vec<_>>(),
+            vec![
+                (0..5, 'a'),
+                (5..7, 'c'),
+                (7..8, 'd'),
+                (8..25, 'c'),
+                (25..30, 'b')
+            ]
+        );
+
+        assert_eq!(map.get(&4), Some(&'a'));
+        assert_eq!(map.get(&7), Some(&'d'));
+        assert_eq!(map.get(&29), Some(&'b'));
+        assert_eq!(map.get(&30), None);
+        assert_eq!(map.range(6..9).count(), 3);
+    }
+
+    #[test]
+    fn test_from_sorted() {
+        let map = IntervalMap::<u32, u32>::from_sorted([(0..10, 1), (10..20, 2)]).unwrap();
+        assert_eq!(map.get(&10), Some(&2));
+
+        assert_eq!(
+            IntervalMap::<u32, u32>::from_sorted([(0..10, 1), (5..20, 2)]).unwrap_err(),
+            IntervalMapError::OverlappingInterval { index: 1 }
+        );
+        assert_eq!(
+            IntervalMap::<u32, u32>::from_sorted([(0..10, 1), (20..20, 2)]).unwrap_err(),
+            IntervalMapError::EmptyInterval { index: 1 }
+        );
+
+        // lookups don't need `Clone` values
+        struct Handle(u32);
+        let map = IntervalMap::<u32, Handle>::from_sorted([(0..10, Handle(1))]).unwrap();
+        assert_eq!(map.get(&5).map(|it| it.0), Some(1));
+    }
+}
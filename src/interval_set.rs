@@ -0,0 +1,295 @@
+use alloc::vec::Vec;
+use core::{
+    cmp::Ordering,
+    fmt::{self, Debug},
+    marker::PhantomData,
+    ops::Range,
+};
+
+use crate::{
+    comparator::{Comparator, OrdComparator},
+    sorted_slice::SortedSlice,
+    sorted_vec::{self, SortedVec},
+    utils::{lt, max, min},
+};
+
+pub struct StartComparator<C>(PhantomData<fn() -> C>);
+
+impl<T, C: Comparator<T>> Comparator<Range<T>> for StartComparator<C> {
+    fn compare(x: &Range<T>, y: &Range<T>) -> Ordering {
+        C::compare(&x.start, &y.start)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct IntervalSet<T, C: Comparator<T> = OrdComparator> {
+    intervals: SortedVec<Range<T>, StartComparator<C>>,
+}
+
+impl<T, C: Comparator<T>> IntervalSet<T, C> {
+    pub fn new() -> Self {
+        IntervalSet {
+            intervals: SortedVec::new(),
+        }
+    }
+
+    pub fn as_sorted_slice(&self) -> &SortedSlice<Range<T>, StartComparator<C>> {
+        self.intervals.as_sorted_slice()
+    }
+
+    pub fn len(&self) -> usize {
+        self.intervals.as_slice().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.as_slice().is_empty()
+    }
+
+    pub fn iter(&self) -> core::slice::Iter<'_, Range<T>> {
+        self.intervals.as_slice().iter()
+    }
+
+    pub fn contains(&self, point: &T) -> bool {
+        let intervals = self.intervals.as_slice();
+        let index = intervals.partition_point(|it| !lt::<T, C>(point, &it.start));
+        index > 0 && lt::<T, C>(point, &intervals[index - 1].end)
+    }
+
+    pub fn overlaps(&self, range: &Range<T>) -> bool {
+        let intervals = self.intervals.as_slice();
+        let index = intervals.partition_point(|it| !lt::<T, C>(&range.start, &it.end));
+        lt::<T, C>(&range.start, &range.end)
+            && intervals
+                .get(index)
+                .is_some_and(|it| lt::<T, C>(&it.start, &range.end))
+    }
+
+    pub fn gaps(&self, within: Range<T>) -> Gaps<'_, T, C> {
+        let intervals = self.intervals.as_slice();
+        let low = intervals.partition_point(|it| !lt::<T, C>(&within.start, &it.end));
+        let high = intervals.partition_point(|it| lt::<T, C>(&it.start, &within.end));
+
+        Gaps {
+            _comparator: PhantomData,
+            intervals: &intervals[low..high.max(low)],
+            next: Some(within.start),
+            end: within.end,
+        }
+    }
+}
+
+impl<T: Clone, C: Comparator<T>> IntervalSet<T, C> {
+    pub fn insert(&mut self, range: Range<T>) {
+        if !lt::<T, C>(&range.start, &range.end) {
+            return;
+        }
+
+        let intervals = self.intervals.as_slice();
+        let low = intervals.partition_point(|it| lt::<T, C>(&it.end, &range.start));
+        let high = intervals.partition_point(|it| !lt::<T, C>(&range.end, &it.start));
+
+        let merged = match intervals[low..high] {
+            [] => range,
+            [ref first, .., ref last] | [ref first @ ref last] => {
+                min::<T, C>(&first.start, &range.start).clone()
+                    ..max::<T, C>(&last.end, &range.end).clone()
+            }
+        };
+
+        sorted_vec::as_mut_vec_unchecked(&mut self.intervals).splice(low..high, [merged]);
+    }
+
+    pub fn remove(&mut self, range: Range<T>) {
+        if !lt::<T, C>(&range.start, &range.end) {
+            return;
+        }
+
+        let intervals = self.intervals.as_slice();
+        let low = intervals.partition_point(|it| !lt::<T, C>(&range.start, &it.end));
+        let high = intervals.partition_point(|it| lt::<T, C>(&it.start, &range.end));
+        if low == high {
+            return;
+        }
+
+        let (first, last) = (&intervals[low], &intervals[high - 1]);
+        let mut remainders = Vec::with_capacity(2);
+        if lt::<T, C>(&first.start, &range.start) {
+            remainders.push(first.start.clone()..range.start.clone());
+        }
+        if lt::<T, C>(&range.end, &last.end) {
+            remainders.push(range.end.clone()..last.end.clone());
+        }
+
+        sorted_vec::as_mut_vec_unchecked(&mut self.intervals).splice(low..high, remainders);
+    }
+
+    pub fn complement(&self, within: Range<T>) -> Self {
+        from_disjoint_unchecked(self.gaps(within).collect())
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let (mut left, mut right) = (self.iter().peekable(), other.iter().peekable());
+        let mut intervals: Vec<Range<T>> = Vec::with_capacity(self.len() + other.len());
+
+        loop {
+            let next = match (left.peek(), right.peek()) {
+                (Some(x), Some(y)) if lt::<T, C>(&y.start, &x.start) => right.next(),
+                (Some(_), _) => left.next(),
+                (None, _) => right.next(),
+            };
+            let Some(next) = next else {
+                break;
+            };
+
+            match intervals.last_mut() {
+                Some(last) if !lt::<T, C>(&last.end, &next.start) => {
+                    last.end = max::<T, C>(&last.end, &next.end).clone();
+                }
+                _ => intervals.push(next.clone()),
+            }
+        }
+
+        from_disjoint_unchecked(intervals)
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let (left, right) = (self.intervals.as_slice(), other.intervals.as_slice());
+        let (mut i, mut j) = (0, 0);
+        let mut intervals = Vec::new();
+
+        while i < left.len() && j < right.len() {
+            let (x, y) = (&left[i], &right[j]);
+
+            let start = max::<T, C>(&x.start, &y.start);
+            let end = min::<T, C>(&x.end, &y.end);
+            if lt::<T, C>(start, end) {
+                intervals.push(start.clone()..end.clone());
+            }
+
+            if lt::<T, C>(&x.end, &y.end) {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        from_disjoint_unchecked(intervals)
+    }
+}
+
+impl<T: Clone, C: Comparator<T>> Clone for IntervalSet<T, C> {
+    fn clone(&self) -> Self {
+        IntervalSet {
+            intervals: self.intervals.clone(),
+        }
+    }
+}
+
+impl<T, C: Comparator<T>> Default for IntervalSet<T, C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Debug, C: Comparator<T>> Debug for IntervalSet<T, C> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_set().entries(self.intervals.as_slice()).finish()
+    }
+}
+
+impl<T: Clone, C: Comparator<T>> Extend<Range<T>> for IntervalSet<T, C> {
+    fn extend<I: IntoIterator<Item = Range<T>>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+impl<T: Clone, C: Comparator<T>> FromIterator<Range<T>> for IntervalSet<T, C> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+pub struct Gaps<'a, T, C> {
+    _comparator: PhantomData<fn() -> C>,
+    intervals: &'a [Range<T>],
+    next: Option<T>,
+    end: T,
+}
+
+impl<T: Clone, C: Comparator<T>> Iterator for Gaps<'_, T, C> {
+    type Item = Range<T>;
+
+    fn next(&mut self) -> Option<Range<T>> {
+        loop {
+            let start = self.next.take()?;
+
+            let Some((interval, rest)) = self.intervals.split_first() else {
+                return lt::<T, C>(&start, &self.end).then(|| start..self.end.clone());
+            };
+            self.intervals = rest;
+            self.next = Some(interval.end.clone());
+
+            if lt::<T, C>(&start, &interval.start) {
+                return Some(start..interval.start.clone());
+            }
+        }
+    }
+}
+
+fn from_disjoint_unchecked<T, C: Comparator<T>>(intervals: Vec<Range<T>>) -> IntervalSet<T, C> {
+    IntervalSet {
+        intervals: sorted_vec::from_vec_unchecked(intervals),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::vec;
+
+    use super::*;
+
+    fn ranges<T: Clone, C: Comparator<T>>(set: &IntervalSet<T, C>) -> Vec<Range<T>> {
+        set.iter().cloned().collect()
+    }
+
+    #[test]
+    fn test_insert_remove() {
+        let mut set: IntervalSet<i32> = [5..10, 0..2, 12..15].into_iter().collect();
+        assert_eq!(ranges(&set), vec![0..2, 5..10, 12..15]);
+
+        set.insert(10..12);
+        assert_eq!(ranges(&set), vec![0..2, 5..15]);
+
+        set.insert(1..6);
+        assert_eq!(ranges(&set), vec![0..15]);
+
+        set.remove(3..4);
+        set.remove(14..20);
+        assert_eq!(ranges(&set), vec![0..3, 4..14]);
+
+        assert!(set.contains(&0));
+        assert!(!set.contains(&3));
+        assert!(!set.contains(&14));
+        assert!(set.overlaps(&(2..4)));
+        assert!(!set.overlaps(&(3..4)));
+        assert!(!set.overlaps(&(20..30)));
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a: IntervalSet<i32> = [0..5, 10..15].into_iter().collect();
+        let b: IntervalSet<i32> = [3..12, 20..25].into_iter().collect();
+
+        assert_eq!(
+            a.gaps(-5..30).collect::<Vec<_>>(),
+            vec![-5..0, 5..10, 15..30]
+        );
+        assert_eq!(ranges(&a.complement(2..12)), vec![5..10]);
+        assert_eq!(ranges(&a.union(&b)), vec![0..15, 20..25]);
+        assert_eq!(ranges(&a.intersection(&b)), vec![3..5, 10..12]);
+    }
+}
@@ -0,0 +1,466 @@
+#[cfg(feature = "alloc")]
+use alloc::{collections::BinaryHeap, vec::Vec};
+use core::{
+    cmp::Ordering,
+    iter::{Cloned, Copied, Filter, Peekable, Skip, SkipWhile, StepBy, Take, TakeWhile},
+    marker::PhantomData,
+};
+
+use crate::comparator::{Comparator, RefComparator};
+#[cfg(feature = "alloc")]
+use crate::sorted_vec::{from_vec_unchecked, sort_vec, SortedVec};
+
+pub trait SortedIteratorExt: Iterator {
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn collect_sorted<C: Comparator<Self::Item>>(self) -> SortedVec<Self::Item, C>
+    where
+        Self: Sized,
+    {
+        sort_vec(self.collect())
+    }
+
+    fn assume_sorted<C: Comparator<Self::Item>>(self) -> SortedIter<AssumeSorted<Self, C>, C>
+    where
+        Self: Sized,
+    {
+        from_sorted_iter_unchecked(AssumeSorted {
+            _comparator: PhantomData,
+            iter: self.peekable(),
+        })
+    }
+
+    #[allow(clippy::wrong_self_convention)]
+    fn is_sorted_by_comparator<C: Comparator<Self::Item>>(mut self) -> bool
+    where
+        Self: Sized,
+    {
+        let Some(mut prev) = self.next() else {
+            return true;
+        };
+
+        for item in self {
+            if C::compare(&prev, &item) == Ordering::Greater {
+                return false;
+            }
+            prev = item;
+        }
+
+        true
+    }
+}
+
+impl<I: Iterator> SortedIteratorExt for I {}
+
+pub struct SortedIter<I, C> {
+    _comparator: PhantomData<fn() -> C>,
+    iter: I,
+}
+
+impl<I: Iterator, C> SortedIter<I, C> {
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+
+    pub fn filter<P>(self, predicate: P) -> SortedIter<Filter<I, P>, C>
+    where
+        P: FnMut(&I::Item) -> bool,
+    {
+        from_sorted_iter_unchecked(self.iter.filter(predicate))
+    }
+
+    pub fn take_while<P>(self, predicate: P) -> SortedIter<TakeWhile<I, P>, C>
+    where
+        P: FnMut(&I::Item) -> bool,
+    {
+        from_sorted_iter_unchecked(self.iter.take_while(predicate))
+    }
+
+    pub fn skip_while<P>(self, predicate: P) -> SortedIter<SkipWhile<I, P>, C>
+    where
+        P: FnMut(&I::Item) -> bool,
+    {
+        from_sorted_iter_unchecked(self.iter.skip_while(predicate))
+    }
+
+    pub fn skip(self, n: usize) -> SortedIter<Skip<I>, C> {
+        from_sorted_iter_unchecked(self.iter.skip(n))
+    }
+
+    pub fn take(self, n: usize) -> SortedIter<Take<I>, C> {
+        from_sorted_iter_unchecked(self.iter.take(n))
+    }
+
+    pub fn step_by(self, step: usize) -> SortedIter<StepBy<I>, C> {
+        from_sorted_iter_unchecked(self.iter.step_by(step))
+    }
+
+    pub fn dedup(self) -> SortedIter<Dedup<I, C>, C>
+    where
+        C: Comparator<I::Item>,
+    {
+        from_sorted_iter_unchecked(Dedup {
+            _comparator: PhantomData,
+            iter: self.iter.peekable(),
+        })
+    }
+
+    pub fn merge<J>(self, other: SortedIter<J, C>) -> SortedIter<Merge<I, J, C>, C>
+    where
+        J: Iterator<Item = I::Item>,
+        C: Comparator<I::Item>,
+    {
+        from_sorted_iter_unchecked(Merge {
+            _comparator: PhantomData,
+            left: self.iter.peekable(),
+            right: other.iter.peekable(),
+        })
+    }
+
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn collect_sorted(self) -> SortedVec<I::Item, C>
+    where
+        C: Comparator<I::Item>,
+    {
+        let mut vec = Vec::with_capacity(self.iter.size_hint().0);
+        vec.extend(self.iter);
+        from_vec_unchecked(vec)
+    }
+}
+
+impl<'a, T: 'a, I: Iterator<Item = &'a T>, C: Comparator<T>> SortedIter<I, RefComparator<C>> {
+    pub fn cloned(self) -> SortedIter<Cloned<I>, C>
+    where
+        T: Clone,
+    {
+        from_sorted_iter_unchecked(self.iter.cloned())
+    }
+
+    pub fn copied(self) -> SortedIter<Copied<I>, C>
+    where
+        T: Copy,
+    {
+        from_sorted_iter_unchecked(self.iter.copied())
+    }
+}
+
+impl<I: Clone, C> Clone for SortedIter<I, C> {
+    fn clone(&self) -> Self {
+        from_sorted_iter_unchecked(self.iter.clone())
+    }
+}
+
+impl<I: Iterator, C> Iterator for SortedIter<I, C> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I: DoubleEndedIterator, C> DoubleEndedIterator for SortedIter<I, C> {
+    fn next_back(&mut self) -> Option<I::Item> {
+        self.iter.next_back()
+    }
+}
+
+impl<I: ExactSizeIterator, C> ExactSizeIterator for SortedIter<I, C> {}
+
+pub struct AssumeSorted<I: Iterator, C> {
+    _comparator: PhantomData<fn() -> C>,
+    iter: Peekable<I>,
+}
+
+impl<I: Iterator, C: Comparator<I::Item>> Iterator for AssumeSorted<I, C> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let item = self.iter.next()?;
+
+        if let Some(next) = self.iter.peek() {
+            if C::compare(&item, next) == Ordering::Greater {
+                panic!("iterator is not sorted");
+            }
+        }
+
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+pub struct Dedup<I: Iterator, C> {
+    _comparator: PhantomData<fn() -> C>,
+    iter: Peekable<I>,
+}
+
+impl<I: Iterator, C: Comparator<I::Item>> Iterator for Dedup<I, C> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let item = self.iter.next()?;
+        while self
+            .iter
+            .next_if(|next| C::compare(&item, next) == Ordering::Equal)
+            .is_some()
+        {}
+
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (low, high) = self.iter.size_hint();
+        (low.min(1), high)
+    }
+}
+
+pub struct Merge<I: Iterator, J: Iterator<Item = I::Item>, C> {
+    _comparator: PhantomData<fn() -> C>,
+    left: Peekable<I>,
+    right: Peekable<J>,
+}
+
+impl<I, J, C> Iterator for Merge<I, J, C>
+where
+    I: Iterator,
+    J: Iterator<Item = I::Item>,
+    C: Comparator<I::Item>,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        match (self.left.peek(), self.right.peek()) {
+            (Some(left), Some(right)) if C::compare(left, right) == Ordering::Greater => {
+                self.right.next()
+            }
+            (Some(_), _) => self.left.next(),
+            (None, _) => self.right.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (left_low, left_high) = self.left.size_hint();
+        let (right_low, right_high) = self.right.size_hint();
+
+        let low = left_low.saturating_add(right_low);
+        let high = match (left_high, right_high) {
+            (Some(left), Some(right)) => left.checked_add(right),
+            _ => None,
+        };
+
+        (low, high)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct MergeAll<I: Iterator, C> {
+    heap: BinaryHeap<MergeEntry<I::Item, C>>,
+    sources: Vec<I>,
+}
+
+#[cfg(feature = "alloc")]
+impl<I: Iterator, C: Comparator<I::Item>> Iterator for MergeAll<I, C> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let MergeEntry { item, source, .. } = self.heap.pop()?;
+
+        if let Some(next) = self.sources[source].next() {
+            self.heap.push(MergeEntry::new(next, source));
+        }
+
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.sources.iter().fold(
+            (self.heap.len(), Some(self.heap.len())),
+            |(low, high), source| {
+                let (source_low, source_high) = source.size_hint();
+                (
+                    low.saturating_add(source_low),
+                    high.zip(source_high)
+                        .and_then(|(high, source_high)| high.checked_add(source_high)),
+                )
+            },
+        )
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: ExactSizeIterator, C: Comparator<I::Item>> ExactSizeIterator for MergeAll<I, C> {}
+
+#[cfg(feature = "alloc")]
+struct MergeEntry<T, C> {
+    _comparator: PhantomData<fn() -> C>,
+    item: T,
+    source: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl<T, C> MergeEntry<T, C> {
+    fn new(item: T, source: usize) -> Self {
+        MergeEntry {
+            _comparator: PhantomData,
+            item,
+            source,
+        }
+    }
+}
+
+// `BinaryHeap` is a max-heap, so entries are ordered in reverse to pop the smallest item first,
+// with ties broken by the index of the source they came from.
+#[cfg(feature = "alloc")]
+impl<T, C: Comparator<T>> Ord for MergeEntry<T, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        C::compare(&other.item, &self.item).then_with(|| other.source.cmp(&self.source))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, C: Comparator<T>> PartialOrd for MergeEntry<T, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, C: Comparator<T>> PartialEq for MergeEntry<T, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, C: Comparator<T>> Eq for MergeEntry<T, C> {}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn merge_all<S, J, I, C>(inputs: J) -> SortedIter<MergeAll<I, C>, C>
+where
+    J: IntoIterator<Item = S>,
+    S: IntoIterator<IntoIter = SortedIter<I, C>>,
+    I: Iterator,
+    C: Comparator<I::Item>,
+{
+    let sources: Vec<I> = inputs
+        .into_iter()
+        .map(|input| input.into_iter().into_inner())
+        .collect();
+
+    let mut merge = MergeAll {
+        heap: BinaryHeap::with_capacity(sources.len()),
+        sources,
+    };
+    for (source, iter) in merge.sources.iter_mut().enumerate() {
+        if let Some(item) = iter.next() {
+            merge.heap.push(MergeEntry::new(item, source));
+        }
+    }
+
+    from_sorted_iter_unchecked(merge)
+}
+
+pub(crate) fn from_sorted_iter_unchecked<I, C>(iter: I) -> SortedIter<I, C> {
+    SortedIter {
+        _comparator: PhantomData,
+        iter,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::comparator::OrdComparator;
+
+    #[test]
+    fn test_is_sorted_by_comparator() {
+        assert!([1, 2, 2, 3]
+            .into_iter()
+            .is_sorted_by_comparator::<OrdComparator>());
+        assert!(
+            !([1, 3, 2]
+                .into_iter()
+                .is_sorted_by_comparator::<OrdComparator>())
+        );
+        assert!(core::iter::empty::<i32>().is_sorted_by_comparator::<OrdComparator>());
+    }
+
+    #[test]
+    #[should_panic(expected = "iterator is not sorted")]
+    fn test_assume_sorted_panics() {
+        [1, 3, 2]
+            .into_iter()
+            .assume_sorted::<OrdComparator>()
+            .for_each(drop);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_merge_dedup() {
+        let a = sorted_array![1, 3, 3, 5];
+        let b = sorted_vec![2, 3, 6];
+
+        let merged = a
+            .iter()
+            .copied()
+            .merge(b.into_iter())
+            .dedup()
+            .filter(|it| *it != 5)
+            .collect_sorted();
+
+        assert_eq!(merged.as_slice(), &[1, 2, 3, 6]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_merge_all() {
+        struct ByKey;
+
+        impl Comparator<(i32, char)> for ByKey {
+            fn compare(x: &(i32, char), y: &(i32, char)) -> Ordering {
+                Ord::cmp(&x.0, &y.0)
+            }
+        }
+
+        let a = sorted_vec![@ByKey; (1, 'a'), (3, 'a'), (5, 'a')];
+        let b = sorted_vec![@ByKey; (2, 'b'), (3, 'b')];
+        let c = sorted_vec![@ByKey; (0, 'c'), (3, 'c'), (9, 'c')];
+
+        let singles = [
+            sorted_vec![@ByKey; (1, 'x')],
+            sorted_vec![@ByKey; (0, 'y')],
+            sorted_vec![@ByKey; (2, 'z')],
+        ];
+        let merged = merge_all(singles).collect_sorted();
+        assert_eq!(merged.capacity(), 3);
+        assert_eq!(merged.as_slice(), &[(0, 'y'), (1, 'x'), (2, 'z')]);
+
+        let merged = merge_all([a, b, c]);
+        assert_eq!(merged.len(), 8);
+
+        let merged = merged.collect_sorted();
+        assert_eq!(merged.capacity(), 8);
+        assert_eq!(
+            merged.as_slice(),
+            &[
+                (0, 'c'),
+                (1, 'a'),
+                (2, 'b'),
+                (3, 'a'),
+                (3, 'b'),
+                (3, 'c'),
+                (5, 'a'),
+                (9, 'c'),
+            ]
+        );
+    }
+}
@@ -0,0 +1,335 @@
+use core::{cmp::Ordering, marker::PhantomData};
+
+use crate::{
+    comparator::Comparator,
+    metric::Metric,
+    sorted_slice::SortedSlice,
+    utils::{gallop_partition_point, gallop_partition_point_rev},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JoinKind {
+    Inner,
+    LeftOuter,
+    RightOuter,
+    FullOuter,
+}
+
+impl JoinKind {
+    fn keeps_left(self) -> bool {
+        matches!(self, JoinKind::LeftOuter | JoinKind::FullOuter)
+    }
+
+    fn keeps_right(self) -> bool {
+        matches!(self, JoinKind::RightOuter | JoinKind::FullOuter)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AsofDirection {
+    Backward,
+    Forward,
+    Nearest,
+}
+
+impl<A, CA: Comparator<A>> SortedSlice<A, CA> {
+    pub fn merge_join<'a, 'b, B, CB, K, FA, FB>(
+        &'a self,
+        other: &'b SortedSlice<B, CB>,
+        kind: JoinKind,
+        left_key: FA,
+        right_key: FB,
+    ) -> MergeJoin<'a, 'b, A, B, CA, CB, K, FA, FB>
+    where
+        CB: Comparator<B>,
+        CA: Comparator<K>,
+        FA: FnMut(&A) -> K,
+        FB: FnMut(&B) -> K,
+    {
+        MergeJoin {
+            _key: PhantomData,
+            left: self,
+            right: other,
+            kind,
+            left_key,
+            right_key,
+            current: Current::None,
+        }
+    }
+
+    pub fn asof_join<'a, 'b, B, CB, K, FA, FB>(
+        &'a self,
+        other: &'b SortedSlice<B, CB>,
+        direction: AsofDirection,
+        max_gap: Option<K::Distance>,
+        left_key: FA,
+        right_key: FB,
+    ) -> AsofJoin<'a, 'b, A, B, CA, CB, K, FA, FB>
+    where
+        CB: Comparator<B>,
+        CA: Comparator<K>,
+        K: Metric,
+        FA: FnMut(&A) -> K,
+        FB: FnMut(&B) -> K,
+    {
+        AsofJoin {
+            left: self,
+            right: other,
+            cursor: 0,
+            direction,
+            max_gap,
+            left_key,
+            right_key,
+        }
+    }
+}
+
+pub struct MergeJoin<'a, 'b, A, B, CA: Comparator<A>, CB: Comparator<B>, K, FA, FB> {
+    _key: PhantomData<fn() -> K>,
+    left: &'a SortedSlice<A, CA>,
+    right: &'b SortedSlice<B, CB>,
+    kind: JoinKind,
+    left_key: FA,
+    right_key: FB,
+    current: Current<'a, 'b, A, B, CA, CB>,
+}
+
+enum Current<'a, 'b, A, B, CA: Comparator<A>, CB: Comparator<B>> {
+    None,
+    Both(&'a SortedSlice<A, CA>, &'b SortedSlice<B, CB>, usize, usize),
+    Left(&'a SortedSlice<A, CA>, usize),
+    Right(&'b SortedSlice<B, CB>, usize),
+}
+
+impl<'a, 'b, A, B, CA, CB, K, FA, FB> Iterator for MergeJoin<'a, 'b, A, B, CA, CB, K, FA, FB>
+where
+    CA: Comparator<A> + Comparator<K>,
+    CB: Comparator<B>,
+    FA: FnMut(&A) -> K,
+    FB: FnMut(&B) -> K,
+{
+    type Item = (Option<&'a A>, Option<&'b B>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match &mut self.current {
+                Current::Both(left, right, i, j) if *i < left.len() => {
+                    let item = (Some(&left[*i]), Some(&right[*j]));
+                    *j += 1;
+                    if *j == right.len() {
+                        *j = 0;
+                        *i += 1;
+                    }
+                    return Some(item);
+                }
+                Current::Left(left, i) if *i < left.len() => {
+                    *i += 1;
+                    return Some((Some(&left[*i - 1]), None));
+                }
+                Current::Right(right, j) if *j < right.len() => {
+                    *j += 1;
+                    return Some((None, Some(&right[*j - 1])));
+                }
+                _ => {}
+            }
+
+            let (left, right) = (self.left, self.right);
+            let (left_key, right_key) = (&mut self.left_key, &mut self.right_key);
+
+            self.current = match (left.first(), right.first()) {
+                (None, None) => return None,
+                (Some(_), None) if self.kind.keeps_left() => {
+                    self.left = &left[left.len()..];
+                    Current::Left(left, 0)
+                }
+                (None, Some(_)) if self.kind.keeps_right() => {
+                    self.right = &right[right.len()..];
+                    Current::Right(right, 0)
+                }
+                (Some(_), None) | (None, Some(_)) => return None,
+                (Some(a), Some(b)) => {
+                    let (a, b) = (left_key(a), right_key(b));
+
+                    match CA::compare(&a, &b) {
+                        Ordering::Less => {
+                            let end = gallop_partition_point(left, |it| {
+                                CA::compare(&left_key(it), &b) == Ordering::Less
+                            });
+                            self.left = &left[end..];
+                            if self.kind.keeps_left() {
+                                Current::Left(&left[..end], 0)
+                            } else {
+                                Current::None
+                            }
+                        }
+                        Ordering::Greater => {
+                            let end = gallop_partition_point(right, |it| {
+                                CA::compare(&right_key(it), &a) == Ordering::Less
+                            });
+                            self.right = &right[end..];
+                            if self.kind.keeps_right() {
+                                Current::Right(&right[..end], 0)
+                            } else {
+                                Current::None
+                            }
+                        }
+                        Ordering::Equal => {
+                            let left_end = gallop_partition_point(left, |it| {
+                                CA::compare(&left_key(it), &a) == Ordering::Equal
+                            });
+                            let right_end = gallop_partition_point(right, |it| {
+                                CA::compare(&right_key(it), &b) == Ordering::Equal
+                            });
+                            self.left = &left[left_end..];
+                            self.right = &right[right_end..];
+                            Current::Both(&left[..left_end], &right[..right_end], 0, 0)
+                        }
+                    }
+                }
+            };
+        }
+    }
+}
+
+pub struct AsofJoin<'a, 'b, A, B, CA: Comparator<A>, CB: Comparator<B>, K: Metric, FA, FB> {
+    left: &'a SortedSlice<A, CA>,
+    right: &'b SortedSlice<B, CB>,
+    cursor: usize,
+    direction: AsofDirection,
+    max_gap: Option<K::Distance>,
+    left_key: FA,
+    right_key: FB,
+}
+
+impl<'a, 'b, A, B, CA, CB, K, FA, FB> Iterator for AsofJoin<'a, 'b, A, B, CA, CB, K, FA, FB>
+where
+    CA: Comparator<A> + Comparator<K>,
+    CB: Comparator<B>,
+    K: Metric,
+    FA: FnMut(&A) -> K,
+    FB: FnMut(&B) -> K,
+{
+    type Item = (&'a A, Option<&'b B>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let left = self.left;
+        let item = left.first()?;
+        self.left = &left[1..];
+
+        let key = (self.left_key)(item);
+        let right_key = &mut self.right_key;
+
+        // `cursor` is the number of right items whose key is at or before the current key
+        self.cursor += gallop_partition_point(&self.right[self.cursor..], |it| {
+            CA::compare(&right_key(it), &key) != Ordering::Greater
+        });
+
+        let backward = self.cursor.checked_sub(1).map(|index| &self.right[index]);
+
+        // the first right item whose key is at or after the current key, which is the start of
+        // the equal group ending at `cursor` if there is one
+        let forward_index = gallop_partition_point_rev(&self.right[..self.cursor], |it| {
+            CA::compare(&right_key(it), &key) == Ordering::Less
+        });
+        let forward = self.right.get(forward_index);
+
+        let found = match self.direction {
+            AsofDirection::Backward => backward,
+            AsofDirection::Forward => forward,
+            AsofDirection::Nearest => match (backward, forward) {
+                (Some(backward), Some(forward)) => {
+                    let backward_distance = key.distance(&right_key(backward));
+                    let forward_distance = key.distance(&right_key(forward));
+                    if forward_distance < backward_distance {
+                        Some(forward)
+                    } else {
+                        Some(backward)
+                    }
+                }
+                (backward, forward) => backward.or(forward),
+            },
+        };
+
+        let found = found.filter(|it| match self.max_gap {
+            Some(max_gap) => key.distance(&right_key(it)) <= max_gap,
+            None => true,
+        });
+
+        Some((item, found))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.left.len(), Some(self.left.len()))
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod test {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    fn join(kind: JoinKind) -> Vec<(Option<char>, Option<char>)> {
+        let left = sorted_array![(1, 'a'), (2, 'b'), (2, 'c'), (4, 'd')];
+        let right = sorted_array![(0, 'w'), (2, 'x'), (2, 'y'), (4, 'z')];
+
+        left.merge_join(&right, kind, |it| it.0, |it| it.0)
+            .map(|(a, b)| (a.map(|a| a.1), b.map(|b| b.1)))
+            .collect()
+    }
+
+    fn asof_join(direction: AsofDirection, max_gap: Option<u32>) -> Vec<Option<char>> {
+        let trades = sorted_array![1, 5, 10, 11, 20];
+        let quotes = sorted_array![(2, 'a'), (5, 'b'), (5, 'c'), (9, 'd'), (14, 'e')];
+
+        trades
+            .asof_join(&quotes, direction, max_gap, |it| *it, |it| it.0)
+            .map(|(_, quote)| quote.map(|quote| quote.1))
+            .collect()
+    }
+
+    #[test]
+    fn test_asof_join() {
+        assert_eq!(
+            asof_join(AsofDirection::Backward, None),
+            [None, Some('c'), Some('d'), Some('d'), Some('e')]
+        );
+        assert_eq!(
+            asof_join(AsofDirection::Forward, None),
+            [Some('a'), Some('b'), Some('e'), Some('e'), None]
+        );
+        assert_eq!(
+            asof_join(AsofDirection::Nearest, None),
+            [Some('a'), Some('c'), Some('d'), Some('d'), Some('e')]
+        );
+        assert_eq!(
+            asof_join(AsofDirection::Backward, Some(1)),
+            [None, Some('c'), Some('d'), None, None]
+        );
+    }
+
+    #[test]
+    fn test_merge_join() {
+        let inner = [
+            (Some('b'), Some('x')),
+            (Some('b'), Some('y')),
+            (Some('c'), Some('x')),
+            (Some('c'), Some('y')),
+            (Some('d'), Some('z')),
+        ];
+
+        assert_eq!(join(JoinKind::Inner), inner);
+        assert_eq!(
+            join(JoinKind::LeftOuter)[..],
+            [&[(Some('a'), None)], &inner[..]].concat()
+        );
+        assert_eq!(
+            join(JoinKind::RightOuter)[..],
+            [&[(None, Some('w'))], &inner[..]].concat()
+        );
+        assert_eq!(
+            join(JoinKind::FullOuter)[..],
+            [&[(None, Some('w')), (Some('a'), None)], &inner[..]].concat()
+        );
+    }
+}
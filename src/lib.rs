@@ -4,24 +4,85 @@
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
+#[macro_use]
+mod macros;
+
+#[cfg(feature = "alloc")]
+mod aggregate;
+mod bucket;
+#[cfg(feature = "codegen")]
+#[cfg_attr(docsrs, doc(cfg(feature = "codegen")))]
+pub mod codegen;
 mod comparator;
+mod cursor;
+mod diff;
+mod finger;
+#[cfg(feature = "alloc")]
+mod interval_index;
+#[cfg(feature = "alloc")]
+mod interval_map;
+#[cfg(feature = "alloc")]
+mod interval_set;
+mod iter;
+mod join;
+#[cfg(feature = "alloc")]
+mod lookup_table;
+mod metric;
+mod nearest;
+mod prefix;
+mod runs;
 mod sorted_array;
+mod sorted_collection;
 mod sorted_slice;
 #[cfg(feature = "alloc")]
 mod sorted_vec;
+mod stats;
 mod utils;
 mod weak_borrow;
 
+#[cfg(feature = "alloc")]
+pub use crate::aggregate::*;
+pub use crate::bucket::*;
 pub use crate::comparator::*;
+pub use crate::cursor::*;
+pub use crate::diff::*;
+pub use crate::finger::*;
+#[cfg(feature = "alloc")]
+pub use crate::interval_index::*;
+#[cfg(feature = "alloc")]
+pub use crate::interval_map::*;
+#[cfg(feature = "alloc")]
+pub use crate::interval_set::*;
+pub use crate::iter::*;
+pub use crate::join::*;
+#[cfg(feature = "alloc")]
+pub use crate::lookup_table::*;
+pub use crate::metric::*;
+pub use crate::prefix::*;
+pub use crate::runs::*;
 pub use crate::sorted_array::*;
+pub use crate::sorted_collection::*;
 pub use crate::sorted_slice::*;
 #[cfg(feature = "alloc")]
 pub use crate::sorted_vec::*;
+pub use crate::stats::*;
 pub use crate::weak_borrow::*;
 
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "alloc")]
+    pub use alloc::vec::Vec;
+
+    pub use crate::macros::sort_array;
+}
+
 pub mod prelude {
+    pub use crate::iter::SortedIteratorExt;
     pub use crate::sorted_array::SortedArray;
+    pub use crate::sorted_collection::SortedCollection;
     pub use crate::sorted_slice::SortedSlice;
     #[cfg(feature = "alloc")]
     pub use crate::sorted_vec::SortedVec;
@@ -0,0 +1,162 @@
+use core::{cmp::Ordering, marker::PhantomData};
+
+use crate::{
+    comparator::Comparator,
+    sorted_vec::{sort_vec, SortedVec},
+    stats::ToF64,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Interpolation {
+    Linear,
+    StepLeft,
+    StepRight,
+    Nearest,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Extrapolation {
+    Clamp,
+    Extend,
+    Error,
+}
+
+pub struct ByX<X, Y>(PhantomData<fn() -> (X, Y)>);
+
+impl<X: ToF64, Y> Comparator<(X, Y)> for ByX<X, Y> {
+    fn compare(a: &(X, Y), b: &(X, Y)) -> Ordering {
+        a.0.to_f64().total_cmp(&b.0.to_f64())
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct LookupTable<X: ToF64, Y> {
+    points: SortedVec<(X, Y), ByX<X, Y>>,
+    interpolation: Interpolation,
+    extrapolation: Extrapolation,
+}
+
+impl<X: ToF64, Y: ToF64> LookupTable<X, Y> {
+    pub fn new<I: IntoIterator<Item = (X, Y)>>(points: I) -> Self {
+        LookupTable {
+            points: sort_vec(points.into_iter().collect()),
+            interpolation: Interpolation::Linear,
+            extrapolation: Extrapolation::Clamp,
+        }
+    }
+
+    pub fn with_interpolation(mut self, interpolation: Interpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    pub fn with_extrapolation(mut self, extrapolation: Extrapolation) -> Self {
+        self.extrapolation = extrapolation;
+        self
+    }
+
+    pub fn points(&self) -> &SortedVec<(X, Y), ByX<X, Y>> {
+        &self.points
+    }
+
+    pub fn eval(&self, x: f64) -> Option<f64> {
+        let points = self.points.as_slice();
+        let (first, last) = (points.first()?, points.last()?);
+
+        if x < first.0.to_f64() || x > last.0.to_f64() {
+            match self.extrapolation {
+                Extrapolation::Error => return None,
+                Extrapolation::Clamp if x < first.0.to_f64() => return Some(first.1.to_f64()),
+                Extrapolation::Clamp => return Some(last.1.to_f64()),
+                Extrapolation::Extend => {}
+            }
+        }
+
+        // index of the first point to the right of `x`, clamped so that there is always a
+        // segment to interpolate (or extend) along if there are at least two points
+        let index = points
+            .partition_point(|point| point.0.to_f64() <= x)
+            .clamp(1, points.len().max(2) - 1);
+        let left = &points[index - 1];
+        let right = points.get(index).unwrap_or(left);
+
+        let (x0, y0) = (left.0.to_f64(), left.1.to_f64());
+        let (x1, y1) = (right.0.to_f64(), right.1.to_f64());
+
+        let y = match self.interpolation {
+            _ if x1 == x0 => y0,
+            Interpolation::Linear => y0 + (y1 - y0) * (x - x0) / (x1 - x0),
+            Interpolation::StepLeft if x < x1 => y0,
+            Interpolation::StepLeft => y1,
+            Interpolation::StepRight if x > x0 => y1,
+            Interpolation::StepRight => y0,
+            Interpolation::Nearest if x - x0 <= x1 - x => y0,
+            Interpolation::Nearest => y1,
+        };
+
+        Some(y)
+    }
+}
+
+impl<X: ToF64 + Clone, Y: ToF64 + Clone> LookupTable<X, Y> {
+    pub fn inverse(&self) -> Option<LookupTable<Y, X>> {
+        let points = self.points.as_slice();
+
+        let increasing = points.windows(2).all(|w| w[0].1.to_f64() < w[1].1.to_f64());
+        let decreasing = points.windows(2).all(|w| w[0].1.to_f64() > w[1].1.to_f64());
+        if !increasing && !decreasing {
+            return None;
+        }
+
+        Some(
+            LookupTable::new(points.iter().map(|(x, y)| (y.clone(), x.clone())))
+                .with_interpolation(self.interpolation)
+                .with_extrapolation(self.extrapolation),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_eval() {
+        let table = LookupTable::new([(2.0, 20.0), (0.0, 0.0), (1.0, 5.0)]);
+
+        assert_eq!(table.eval(0.5), Some(2.5));
+        assert_eq!(table.eval(1.5), Some(12.5));
+        assert_eq!(table.eval(-1.0), Some(0.0));
+        assert_eq!(table.eval(3.0), Some(20.0));
+
+        let table = table.with_extrapolation(Extrapolation::Extend);
+        assert_eq!(table.eval(-1.0), Some(-5.0));
+        assert_eq!(table.eval(3.0), Some(35.0));
+
+        let table = table.with_extrapolation(Extrapolation::Error);
+        assert_eq!(table.eval(3.0), None);
+
+        let table = table.with_interpolation(Interpolation::StepLeft);
+        assert_eq!(table.eval(0.9), Some(0.0));
+        assert_eq!(table.eval(1.0), Some(5.0));
+
+        let table = table.with_interpolation(Interpolation::StepRight);
+        assert_eq!(table.eval(0.1), Some(5.0));
+        assert_eq!(table.eval(1.0), Some(5.0));
+
+        let table = table.with_interpolation(Interpolation::Nearest);
+        assert_eq!(table.eval(1.4), Some(5.0));
+        assert_eq!(table.eval(1.6), Some(20.0));
+    }
+
+    #[test]
+    fn test_inverse() {
+        let table = LookupTable::new([(0u32, 0.0), (1, 5.0), (2, 20.0)]);
+        let inverse = table.inverse().unwrap();
+        assert_eq!(inverse.eval(12.5), Some(1.5));
+
+        assert!(LookupTable::new([(0, 1), (1, 0), (2, 1)])
+            .inverse()
+            .is_none());
+    }
+}
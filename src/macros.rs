@@ -0,0 +1,97 @@
+use crate::{comparator::Comparator, sorted_array::SortedArray};
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[macro_export]
+macro_rules! sorted_vec {
+    () => {
+        $crate::SortedVec::<_, $crate::OrdComparator>::new()
+    };
+    (@ $comparator:path; $($item:expr),* $(,)?) => {
+        $crate::sort_vec::<_, $comparator>($crate::__private::Vec::from([$($item),*]))
+    };
+    ($($item:expr),+ $(,)?) => {
+        $crate::sort_vec::<_, $crate::OrdComparator>($crate::__private::Vec::from([$($item),+]))
+    };
+}
+
+#[macro_export]
+macro_rules! sorted_array {
+    () => {
+        $crate::__private::sort_array::<_, 0, $crate::OrdComparator>([])
+    };
+    (@ $comparator:path; $($item:expr),* $(,)?) => {
+        $crate::__private::sort_array::<_, _, $comparator>([$($item),*])
+    };
+    ($($item:expr),* $(,)?) => {
+        $crate::__private::sort_array::<_, _, $crate::OrdComparator>([$($item),*])
+    };
+}
+
+#[cfg(feature = "alloc")]
+pub fn sort_array<T, const N: usize, C: Comparator<T>>(array: [T; N]) -> SortedArray<T, N, C> {
+    crate::sorted_array::sort_array(array)
+}
+
+#[cfg(not(feature = "alloc"))]
+pub fn sort_array<T, const N: usize, C: Comparator<T>>(array: [T; N]) -> SortedArray<T, N, C> {
+    crate::sorted_array::sort_array_unstable(array)
+}
+
+#[cfg(test)]
+mod test {
+    use core::cmp::Ordering;
+
+    use crate::comparator::Comparator;
+
+    struct Reverse;
+
+    impl Comparator<i32> for Reverse {
+        fn compare(x: &i32, y: &i32) -> Ordering {
+            Ord::cmp(y, x)
+        }
+    }
+
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+    struct Id(u32);
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_sorted_vec() {
+        let vec: crate::SortedVec<i32> = sorted_vec![];
+        assert!(vec.is_empty());
+        let vec: crate::SortedVec<i32, Reverse> = sorted_vec![@Reverse;];
+        assert!(vec.is_empty());
+        let mut vec = sorted_vec![];
+        vec.insert(3);
+        assert_eq!(vec.as_slice(), &[3]);
+
+        assert_eq!(sorted_vec![3, 1, 2].as_slice(), &[1, 2, 3]);
+        assert_eq!(sorted_vec![@Reverse; 3, 1, 2,].as_slice(), &[3, 2, 1]);
+        assert_eq!(
+            sorted_vec![(2, 'a'), (1, 'b')].as_slice(),
+            &[(1, 'b'), (2, 'a')]
+        );
+        assert_eq!(
+            sorted_vec![Some(3), None, Some(1)].as_slice(),
+            &[None, Some(1), Some(3)]
+        );
+        assert_eq!(sorted_vec![Id(2), Id(1)].as_slice(), &[Id(1), Id(2)]);
+    }
+
+    #[test]
+    fn test_sorted_array() {
+        let array: crate::SortedArray<i32, 0> = sorted_array![];
+        assert!(array.is_empty());
+        let array: crate::SortedArray<i32, 0, Reverse> = sorted_array![@Reverse;];
+        assert!(array.is_empty());
+
+        assert_eq!(sorted_array![3, 1, 2].as_slice(), &[1, 2, 3]);
+        assert_eq!(sorted_array![@Reverse; 3, 1, 2].as_slice(), &[3, 2, 1]);
+        assert_eq!(
+            sorted_array![Some(3), None, Some(1)].as_slice(),
+            &[None, Some(1), Some(3)]
+        );
+        assert_eq!(sorted_array![Id(2), Id(1)].as_slice(), &[Id(1), Id(2)]);
+    }
+}
@@ -0,0 +1,44 @@
+pub trait Metric {
+    type Distance: Copy + PartialOrd;
+
+    fn distance(&self, other: &Self) -> Self::Distance;
+}
+
+macro_rules! impl_metric {
+    ($($ty:ty => $distance:ty),*) => {
+        $(
+            impl Metric for $ty {
+                type Distance = $distance;
+
+                fn distance(&self, other: &Self) -> $distance {
+                    self.abs_diff(*other)
+                }
+            }
+        )*
+    };
+}
+
+impl_metric! {
+    u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128, usize => usize,
+    i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize
+}
+
+macro_rules! impl_float_metric {
+    ($($ty:ty),*) => {
+        $(
+            impl Metric for $ty {
+                type Distance = $ty;
+
+                fn distance(&self, other: &Self) -> $ty {
+                    if self > other {
+                        self - other
+                    } else {
+                        other - self
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_float_metric!(f32, f64);
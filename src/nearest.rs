@@ -0,0 +1,62 @@
+use core::cmp::Ordering;
+
+use crate::{comparator::Comparator, metric::Metric, sorted_slice::SortedSlice};
+
+impl<T: Metric, C: Comparator<T>> SortedSlice<T, C> {
+    pub fn nearest(&self, item: &T) -> Option<&T> {
+        self.k_nearest(item, 1).first()
+    }
+
+    pub fn k_nearest(&self, item: &T, k: usize) -> &SortedSlice<T, C> {
+        let slice = self.as_slice();
+        let mut low = slice.partition_point(|it| C::compare(it, item) == Ordering::Less);
+        let mut high = low;
+
+        while high - low < k {
+            match (low.checked_sub(1), slice.get(high)) {
+                (Some(before), Some(after)) => {
+                    if after.distance(item) < slice[before].distance(item) {
+                        high += 1;
+                    } else {
+                        low = before;
+                    }
+                }
+                (Some(before), None) => low = before,
+                (None, Some(_)) => high += 1,
+                (None, None) => break,
+            }
+        }
+
+        &self[low..high]
+    }
+
+    pub fn within(&self, item: &T, tolerance: T::Distance) -> &SortedSlice<T, C> {
+        let slice = self.as_slice();
+        let low = slice.partition_point(|it| {
+            C::compare(it, item) == Ordering::Less && it.distance(item) > tolerance
+        });
+        let high = slice.partition_point(|it| {
+            C::compare(it, item) != Ordering::Greater || it.distance(item) <= tolerance
+        });
+
+        &self[low..high.max(low)]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn test_nearest() {
+        let array = sorted_array![-10, 0, 4, 6, 7, 20];
+
+        assert_eq!(array.nearest(&5), Some(&4));
+        assert_eq!(array.nearest(&100), Some(&20));
+        assert_eq!(array.nearest(&-100), Some(&-10));
+        assert_eq!(sorted_array![].nearest(&0), None::<&i32>);
+
+        assert_eq!(array.k_nearest(&5, 3).as_slice(), &[4, 6, 7]);
+        assert_eq!(array.k_nearest(&5, 10).as_slice(), array.as_slice());
+        assert_eq!(array.within(&5, 2).as_slice(), &[4, 6, 7]);
+        assert_eq!(array.within(&-3, 2).as_slice(), &[] as &[i32]);
+    }
+}
@@ -0,0 +1,110 @@
+use core::cmp::Ordering;
+
+use crate::{comparator::Comparator, sorted_slice::SortedSlice, weak_borrow::WeakBorrow};
+
+pub trait PrefixKey {
+    fn starts_with(&self, prefix: &Self) -> bool;
+
+    fn common_prefix<'a>(&'a self, other: &Self) -> &'a Self;
+}
+
+impl PrefixKey for str {
+    fn starts_with(&self, prefix: &str) -> bool {
+        str::starts_with(self, prefix)
+    }
+
+    fn common_prefix<'a>(&'a self, other: &str) -> &'a str {
+        let mut len = self.as_bytes().common_prefix(other.as_bytes()).len();
+        while !self.is_char_boundary(len) {
+            len -= 1;
+        }
+        &self[..len]
+    }
+}
+
+impl<T: PartialEq> PrefixKey for [T] {
+    fn starts_with(&self, prefix: &[T]) -> bool {
+        <[T]>::starts_with(self, prefix)
+    }
+
+    fn common_prefix<'a>(&'a self, other: &[T]) -> &'a [T] {
+        let len = self.iter().zip(other).take_while(|(x, y)| x == y).count();
+        &self[..len]
+    }
+}
+
+// Both lookups rely on items sharing a prefix being contiguous, which holds for the
+// lexicographic order of `OrdComparator`. Custom comparators must order items the same way.
+impl<T, C: Comparator<T>> SortedSlice<T, C> {
+    pub fn prefix_range<P>(&self, prefix: &P) -> &SortedSlice<T, C>
+    where
+        P: PrefixKey + ?Sized,
+        T: WeakBorrow<P>,
+        C: Comparator<P>,
+    {
+        let slice = self.as_slice();
+        let low =
+            slice.partition_point(|it| C::compare(it.weak_borrow(), prefix) == Ordering::Less);
+        let high = low + slice[low..].partition_point(|it| it.weak_borrow().starts_with(prefix));
+
+        &self[low..high]
+    }
+
+    pub fn longest_prefix_match<P>(&self, query: &P) -> Option<&T>
+    where
+        P: PrefixKey + ?Sized,
+        T: WeakBorrow<P>,
+        C: Comparator<P>,
+    {
+        let mut query = query;
+        let mut slice = self.as_slice();
+
+        loop {
+            let index = slice
+                .partition_point(|it| C::compare(it.weak_borrow(), query) != Ordering::Greater);
+            let candidate = slice[..index].last()?;
+
+            if query.starts_with(candidate.weak_borrow()) {
+                return Some(candidate);
+            }
+
+            // any prefix of `query` that is in the slice must also be a prefix of the
+            // candidate, and sorts before it
+            query = query.common_prefix(candidate.weak_borrow());
+            slice = &slice[..index - 1];
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn test_prefix_range() {
+        let array = sorted_array!["foo", "foo/bar", "foo/baz", "foobar", "fop", "fo"];
+
+        assert_eq!(
+            array.prefix_range("foo/").as_slice(),
+            &["foo/bar", "foo/baz"]
+        );
+        assert_eq!(array.prefix_range("foo").len(), 4);
+        assert_eq!(array.prefix_range("").len(), 6);
+        assert!(array.prefix_range("g").is_empty());
+
+        let bytes = sorted_array![&b"ab"[..], &b"abc"[..], &b"b"[..]];
+        assert_eq!(bytes.prefix_range(&b"ab"[..]).len(), 2);
+    }
+
+    #[test]
+    fn test_longest_prefix_match() {
+        let routes = sorted_array!["/", "/api", "/api/v1", "/static", "/apix"];
+
+        assert_eq!(
+            routes.longest_prefix_match("/api/v1/users"),
+            Some(&"/api/v1")
+        );
+        assert_eq!(routes.longest_prefix_match("/api/v2"), Some(&"/api"));
+        assert_eq!(routes.longest_prefix_match("/apiary"), Some(&"/api"));
+        assert_eq!(routes.longest_prefix_match("/index"), Some(&"/"));
+        assert_eq!(routes.longest_prefix_match("index"), None);
+    }
+}
@@ -0,0 +1,93 @@
+use core::cmp::Ordering;
+
+use crate::{comparator::Comparator, sorted_slice::SortedSlice, utils::gallop_partition_point};
+
+impl<T, C: Comparator<T>> SortedSlice<T, C> {
+    pub fn runs(&self) -> Runs<'_, T, C> {
+        Runs {
+            slice: self,
+            compare: C::compare,
+        }
+    }
+
+    pub fn runs_by_key<K, F>(&self, mut f: F) -> Runs<'_, T, C, impl FnMut(&T, &T) -> Ordering>
+    where
+        F: FnMut(&T) -> K,
+        C: Comparator<K>,
+    {
+        Runs {
+            slice: self,
+            compare: move |x: &T, y: &T| C::compare(&f(x), &f(y)),
+        }
+    }
+
+    pub fn counts(&self) -> Counts<'_, T, C> {
+        Counts { runs: self.runs() }
+    }
+}
+
+pub struct Runs<'a, T, C: Comparator<T>, F = fn(&T, &T) -> Ordering> {
+    slice: &'a SortedSlice<T, C>,
+    compare: F,
+}
+
+impl<'a, T, C: Comparator<T>, F> Iterator for Runs<'a, T, C, F>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    type Item = &'a SortedSlice<T, C>;
+
+    fn next(&mut self) -> Option<&'a SortedSlice<T, C>> {
+        let slice = self.slice;
+        let first = slice.first()?;
+
+        let end = gallop_partition_point(&slice[1..], |it| {
+            (self.compare)(first, it) == Ordering::Equal
+        }) + 1;
+        self.slice = &slice[end..];
+
+        Some(&slice[..end])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.slice.len();
+        (len.min(1), Some(len))
+    }
+}
+
+pub struct Counts<'a, T, C: Comparator<T>> {
+    runs: Runs<'a, T, C>,
+}
+
+impl<'a, T, C: Comparator<T>> Iterator for Counts<'a, T, C> {
+    type Item = (&'a T, usize);
+
+    fn next(&mut self) -> Option<(&'a T, usize)> {
+        self.runs.next().map(|run| (&run[0], run.len()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.runs.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn test_runs() {
+        let array = sorted_array![1, 1, 2, 3, 3, 3, 3, 3, 3, 7];
+
+        let mut runs = array.runs();
+        assert_eq!(runs.next().map(|run| run.as_slice()), Some(&[1, 1][..]));
+        assert_eq!(runs.next().map(|run| run.as_slice()), Some(&[2][..]));
+        assert_eq!(runs.next().map(|run| run.len()), Some(6));
+        assert_eq!(runs.next().map(|run| run.as_slice()), Some(&[7][..]));
+        assert!(runs.next().is_none());
+
+        let mut counts = array.counts();
+        assert_eq!(counts.next(), Some((&1, 2)));
+        assert_eq!(counts.nth(1), Some((&3, 6)));
+
+        assert_eq!(array.runs_by_key(|it| *it / 2).count(), 3);
+    }
+}
@@ -2,12 +2,14 @@ use core::{
     borrow::{Borrow, BorrowMut},
     fmt::{self, Debug},
     marker::PhantomData,
-    ops::{Deref, DerefMut, Index, IndexMut},
+    ops::{Deref, DerefMut, Index, IndexMut, RangeBounds},
 };
 
 use crate::{
-    comparator::{Comparator, OrdComparator},
+    comparator::{Comparator, OrdComparator, RefComparator},
+    iter::{self, SortedIter},
     sorted_slice::{self, SortedSlice},
+    weak_borrow::WeakBorrow,
 };
 
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
@@ -18,6 +20,13 @@ pub struct SortedArray<T, const N: usize, C: Comparator<T> = OrdComparator> {
 }
 
 impl<T, const N: usize, C: Comparator<T>> SortedArray<T, N, C> {
+    /// # Safety
+    ///
+    /// The items of `array` must already be sorted according to `C`.
+    pub const unsafe fn from_array_unchecked(array: [T; N]) -> Self {
+        from_array_unchecked(array)
+    }
+
     pub fn as_sorted_slice(&self) -> &SortedSlice<T, C> {
         sorted_slice::from_slice_unchecked(&self.array)
     }
@@ -26,11 +35,40 @@ impl<T, const N: usize, C: Comparator<T>> SortedArray<T, N, C> {
         sorted_slice::from_mut_slice_unchecked(&mut self.array)
     }
 
+    pub fn find<U>(&self, item: &U) -> Option<&T>
+    where
+        T: WeakBorrow<U>,
+        C: Comparator<U>,
+    {
+        self.as_sorted_slice().find(item)
+    }
+
+    pub fn range<U, R>(&self, range: R) -> &SortedSlice<T, C>
+    where
+        T: WeakBorrow<U>,
+        C: Comparator<U>,
+        R: RangeBounds<U>,
+    {
+        self.as_sorted_slice().range(range)
+    }
+
     #[cfg(feature = "alloc")]
     #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
     pub fn into_boxed_sorted_slice(self) -> alloc::boxed::Box<SortedSlice<T, C>> {
         sorted_slice::from_boxed_slice_unchecked(alloc::boxed::Box::new(self.array))
     }
+
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn into_rc_sorted_slice(self) -> alloc::rc::Rc<SortedSlice<T, C>> {
+        sorted_slice::from_rc_slice_unchecked(alloc::rc::Rc::<[T]>::from(self.array))
+    }
+
+    #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn into_arc_sorted_slice(self) -> alloc::sync::Arc<SortedSlice<T, C>> {
+        sorted_slice::from_arc_slice_unchecked(alloc::sync::Arc::<[T]>::from(self.array))
+    }
 }
 
 impl<T: Copy, const N: usize, C: Comparator<T>> Copy for SortedArray<T, N, C> {}
@@ -73,6 +111,26 @@ impl<T, const N: usize, C: Comparator<T>> DerefMut for SortedArray<T, N, C> {
     }
 }
 
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<T, const N: usize, C: Comparator<T>> From<SortedArray<T, N, C>>
+    for alloc::rc::Rc<SortedSlice<T, C>>
+{
+    fn from(array: SortedArray<T, N, C>) -> alloc::rc::Rc<SortedSlice<T, C>> {
+        array.into_rc_sorted_slice()
+    }
+}
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<T, const N: usize, C: Comparator<T>> From<SortedArray<T, N, C>>
+    for alloc::sync::Arc<SortedSlice<T, C>>
+{
+    fn from(array: SortedArray<T, N, C>) -> alloc::sync::Arc<SortedSlice<T, C>> {
+        array.into_arc_sorted_slice()
+    }
+}
+
 impl<T, const N: usize, C: Comparator<T>> Borrow<[T]> for SortedArray<T, N, C> {
     fn borrow(&self) -> &[T] {
         self.as_slice()
@@ -131,32 +189,32 @@ where
 
 impl<T, const N: usize, C: Comparator<T>> IntoIterator for SortedArray<T, N, C> {
     type Item = T;
-    type IntoIter = core::array::IntoIter<T, N>;
+    type IntoIter = SortedIter<core::array::IntoIter<T, N>, C>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.array.into_iter()
+        iter::from_sorted_iter_unchecked(self.array.into_iter())
     }
 }
 
 impl<'a, T, const N: usize, C: Comparator<T>> IntoIterator for &'a SortedArray<T, N, C> {
     type Item = &'a T;
-    type IntoIter = core::slice::Iter<'a, T>;
+    type IntoIter = SortedIter<core::slice::Iter<'a, T>, RefComparator<C>>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.array.iter()
+        self.as_sorted_slice().iter()
     }
 }
 
 impl<'a, T, const N: usize, C: Comparator<T>> IntoIterator for &'a mut SortedArray<T, N, C> {
     type Item = &'a T;
-    type IntoIter = core::slice::Iter<'a, T>;
+    type IntoIter = SortedIter<core::slice::Iter<'a, T>, RefComparator<C>>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.array.iter()
+        self.as_sorted_slice().iter()
     }
 }
 
-pub(crate) fn from_array_unchecked<T, const N: usize, C: Comparator<T>>(
+pub(crate) const fn from_array_unchecked<T, const N: usize, C: Comparator<T>>(
     array: [T; N],
 ) -> SortedArray<T, N, C> {
     SortedArray {
@@ -178,3 +236,117 @@ pub fn sort_array_unstable<T, const N: usize, C: Comparator<T>>(
     array.sort_unstable_by(C::compare);
     from_array_unchecked(array)
 }
+
+macro_rules! impl_const_sorted_array {
+    (($a:ident, $b:ident) => $gt:expr; $($ty:ty),*) => {
+        $(
+            impl<const N: usize> SortedArray<$ty, N> {
+                pub const fn from_sorted(array: [$ty; N]) -> Self {
+                    match Self::try_from_sorted(array) {
+                        Some(array) => array,
+                        None => panic!("array is not sorted"),
+                    }
+                }
+
+                pub const fn try_from_sorted(array: [$ty; N]) -> Option<Self> {
+                    let mut i = 1;
+                    while i < N {
+                        let ($a, $b) = (array[i - 1], array[i]);
+                        if $gt {
+                            return None;
+                        }
+                        i += 1;
+                    }
+
+                    Some(from_array_unchecked(array))
+                }
+
+                pub const fn sort_const(mut array: [$ty; N]) -> Self {
+                    let mut i = 1;
+                    while i < N {
+                        let mut j = i;
+                        while j > 0 {
+                            let ($a, $b) = (array[j - 1], array[j]);
+                            if !$gt {
+                                break;
+                            }
+                            array[j - 1] = $b;
+                            array[j] = $a;
+                            j -= 1;
+                        }
+                        i += 1;
+                    }
+
+                    from_array_unchecked(array)
+                }
+            }
+        )*
+    };
+}
+
+impl_const_sorted_array! {
+    (a, b) => a > b;
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, char
+}
+
+impl_const_sorted_array! {
+    (a, b) => str_gt(a, b);
+    &'static str
+}
+
+const fn str_gt(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+
+    let mut i = 0;
+    while i < a.len() && i < b.len() {
+        if a[i] != b[i] {
+            return a[i] > b[i];
+        }
+        i += 1;
+    }
+
+    a.len() > b.len()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    static NUMBERS: SortedArray<u32, 5> = SortedArray::<u32, 5>::from_sorted([1, 2, 2, 5, 8]);
+    static WORDS: SortedArray<&str, 4> =
+        SortedArray::<&str, 4>::sort_const(["pear", "apple", "app", "banana"]);
+
+    #[test]
+    fn test_const_constructors() {
+        assert!(NUMBERS.contains(&5));
+        assert_eq!(WORDS.as_slice(), &["app", "apple", "banana", "pear"]);
+
+        assert!(SortedArray::<i8, 3>::try_from_sorted([1, 3, 2]).is_none());
+        assert!(SortedArray::<i8, 0>::try_from_sorted([]).is_some());
+        assert_eq!(
+            SortedArray::<i64, 4>::sort_const([3, -1, 7, 0]).as_slice(),
+            &[-1, 0, 3, 7]
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_into_shared_sorted_slice() {
+        use alloc::rc::Rc;
+
+        let rc: Rc<SortedSlice<u32>> = NUMBERS.into();
+        assert_eq!(rc.as_slice(), &[1, 2, 2, 5, 8]);
+        assert_eq!(rc.find(&5), Some(&5));
+        assert_eq!(rc.find(&3), None);
+
+        #[cfg(target_has_atomic = "ptr")]
+        {
+            use alloc::sync::Arc;
+
+            let arc: Arc<SortedSlice<u32>> = NUMBERS.into();
+            assert_eq!(arc.as_slice(), &[1, 2, 2, 5, 8]);
+            assert_eq!(arc.find(&5), Some(&5));
+            assert_eq!(arc.find(&3), None);
+        }
+    }
+}
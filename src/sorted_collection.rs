@@ -0,0 +1,216 @@
+#[cfg(feature = "alloc")]
+use alloc::collections::{btree_map, btree_set, BTreeMap, BTreeSet};
+use core::ops::RangeBounds;
+
+#[cfg(feature = "alloc")]
+use crate::{comparator::OrdComparator, sorted_vec::SortedVec};
+use crate::{
+    comparator::{Comparator, RefComparator},
+    iter::SortedIter,
+    sorted_array::SortedArray,
+    sorted_slice::SortedSlice,
+};
+
+pub trait SortedCollection {
+    type Item;
+    type Comparator: Comparator<Self::Item>;
+
+    type Iter<'a>: Iterator<Item = &'a Self::Item>
+    where
+        Self: 'a;
+
+    type Range<'a>: Iterator<Item = &'a Self::Item>
+    where
+        Self: 'a;
+
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn iter(&self) -> Self::Iter<'_>;
+
+    fn find(&self, item: &Self::Item) -> Option<&Self::Item>;
+
+    fn range<R: RangeBounds<Self::Item>>(&self, range: R) -> Self::Range<'_>;
+}
+
+impl<T, C: Comparator<T>> SortedCollection for SortedSlice<T, C> {
+    type Item = T;
+    type Comparator = C;
+    type Iter<'a>
+        = SortedIter<core::slice::Iter<'a, T>, RefComparator<C>>
+    where
+        Self: 'a;
+    type Range<'a>
+        = SortedIter<core::slice::Iter<'a, T>, RefComparator<C>>
+    where
+        Self: 'a;
+
+    fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        SortedSlice::iter(self)
+    }
+
+    fn find(&self, item: &T) -> Option<&T> {
+        SortedSlice::find(self, item)
+    }
+
+    fn range<R: RangeBounds<T>>(&self, range: R) -> Self::Range<'_> {
+        SortedSlice::range(self, range).iter()
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<T, C: Comparator<T>> SortedCollection for SortedVec<T, C> {
+    type Item = T;
+    type Comparator = C;
+    type Iter<'a>
+        = SortedIter<core::slice::Iter<'a, T>, RefComparator<C>>
+    where
+        Self: 'a;
+    type Range<'a>
+        = SortedIter<core::slice::Iter<'a, T>, RefComparator<C>>
+    where
+        Self: 'a;
+
+    fn len(&self) -> usize {
+        SortedCollection::len(self.as_sorted_slice())
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        SortedCollection::iter(self.as_sorted_slice())
+    }
+
+    fn find(&self, item: &T) -> Option<&T> {
+        SortedCollection::find(self.as_sorted_slice(), item)
+    }
+
+    fn range<R: RangeBounds<T>>(&self, range: R) -> Self::Range<'_> {
+        SortedCollection::range(self.as_sorted_slice(), range)
+    }
+}
+
+impl<T, const N: usize, C: Comparator<T>> SortedCollection for SortedArray<T, N, C> {
+    type Item = T;
+    type Comparator = C;
+    type Iter<'a>
+        = SortedIter<core::slice::Iter<'a, T>, RefComparator<C>>
+    where
+        Self: 'a;
+    type Range<'a>
+        = SortedIter<core::slice::Iter<'a, T>, RefComparator<C>>
+    where
+        Self: 'a;
+
+    fn len(&self) -> usize {
+        N
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        SortedCollection::iter(self.as_sorted_slice())
+    }
+
+    fn find(&self, item: &T) -> Option<&T> {
+        SortedCollection::find(self.as_sorted_slice(), item)
+    }
+
+    fn range<R: RangeBounds<T>>(&self, range: R) -> Self::Range<'_> {
+        SortedCollection::range(self.as_sorted_slice(), range)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<T: Ord> SortedCollection for BTreeSet<T> {
+    type Item = T;
+    type Comparator = OrdComparator;
+    type Iter<'a>
+        = btree_set::Iter<'a, T>
+    where
+        Self: 'a;
+    type Range<'a>
+        = btree_set::Range<'a, T>
+    where
+        Self: 'a;
+
+    fn len(&self) -> usize {
+        BTreeSet::len(self)
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        BTreeSet::iter(self)
+    }
+
+    fn find(&self, item: &T) -> Option<&T> {
+        BTreeSet::get(self, item)
+    }
+
+    fn range<R: RangeBounds<T>>(&self, range: R) -> Self::Range<'_> {
+        BTreeSet::range(self, range)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<K: Ord, V> SortedCollection for BTreeMap<K, V> {
+    type Item = K;
+    type Comparator = OrdComparator;
+    type Iter<'a>
+        = btree_map::Keys<'a, K, V>
+    where
+        Self: 'a;
+    type Range<'a>
+        = core::iter::Map<btree_map::Range<'a, K, V>, fn((&'a K, &'a V)) -> &'a K>
+    where
+        Self: 'a;
+
+    fn len(&self) -> usize {
+        BTreeMap::len(self)
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        BTreeMap::keys(self)
+    }
+
+    fn find(&self, item: &K) -> Option<&K> {
+        BTreeMap::get_key_value(self, item).map(|(key, _)| key)
+    }
+
+    fn range<R: RangeBounds<K>>(&self, range: R) -> Self::Range<'_> {
+        BTreeMap::range(self, range).map(|(key, _)| key)
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod test {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    fn collect<S: SortedCollection<Item = i32> + ?Sized>(collection: &S) -> (usize, Vec<i32>) {
+        assert_eq!(collection.find(&3), Some(&3));
+        assert_eq!(collection.find(&4), None);
+
+        (collection.len(), collection.range(2..=5).copied().collect())
+    }
+
+    #[test]
+    fn test_sorted_collection() {
+        let expected = (4, alloc::vec![3, 5]);
+
+        assert_eq!(collect(&sorted_array![5, 1, 3, 7]), expected);
+        assert_eq!(collect(&sorted_vec![5, 1, 3, 7]), expected);
+        assert_eq!(collect(sorted_vec![5, 1, 3, 7].as_sorted_slice()), expected);
+        assert_eq!(collect(&BTreeSet::from([5, 1, 3, 7])), expected);
+        assert_eq!(
+            collect(&BTreeMap::from([(5, ()), (1, ()), (3, ()), (7, ())])),
+            expected
+        );
+    }
+}
@@ -1,17 +1,19 @@
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use alloc::sync::Arc;
 #[cfg(feature = "alloc")]
-use alloc::boxed::Box;
+use alloc::{boxed::Box, rc::Rc};
 use core::{
     borrow::Borrow,
     cmp::Ordering,
     fmt::{self, Debug},
     marker::PhantomData,
     ops::{
-        Deref, Index, IndexMut, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo,
-        RangeToInclusive,
+        Bound, Deref, Index, IndexMut, Range, RangeBounds, RangeFrom, RangeFull, RangeInclusive,
+        RangeTo, RangeToInclusive,
     },
 };
 
-use crate::{comparator::*, utils::*, weak_borrow::*};
+use crate::{comparator::*, iter::*, utils::*, weak_borrow::*};
 
 #[repr(transparent)]
 pub struct SortedSlice<T, C: Comparator<T> = OrdComparator> {
@@ -24,6 +26,10 @@ impl<T, C: Comparator<T>> SortedSlice<T, C> {
         &self.slice
     }
 
+    pub fn iter(&self) -> SortedIter<core::slice::Iter<'_, T>, RefComparator<C>> {
+        from_sorted_iter_unchecked(self.slice.iter())
+    }
+
     pub fn contains<U>(&self, item: &U) -> bool
     where
         T: WeakBorrow<U>,
@@ -106,6 +112,44 @@ impl<T, C: Comparator<T>> SortedSlice<T, C> {
         self.find_mut_range_by(|it| C::compare(&f(it), key))
     }
 
+    pub fn range<U, R>(&self, range: R) -> &SortedSlice<T, C>
+    where
+        T: WeakBorrow<U>,
+        C: Comparator<U>,
+        R: RangeBounds<U>,
+    {
+        let (low, high) = self.range_indices(range);
+        &self[low..high]
+    }
+
+    pub(crate) fn range_indices<U, R>(&self, range: R) -> (usize, usize)
+    where
+        T: WeakBorrow<U>,
+        C: Comparator<U>,
+        R: RangeBounds<U>,
+    {
+        let low = match range.start_bound() {
+            Bound::Included(start) => self
+                .slice
+                .partition_point(|it| C::compare(it.weak_borrow(), start).is_lt()),
+            Bound::Excluded(start) => self
+                .slice
+                .partition_point(|it| C::compare(it.weak_borrow(), start).is_le()),
+            Bound::Unbounded => 0,
+        };
+        let high = match range.end_bound() {
+            Bound::Included(end) => self
+                .slice
+                .partition_point(|it| C::compare(it.weak_borrow(), end).is_le()),
+            Bound::Excluded(end) => self
+                .slice
+                .partition_point(|it| C::compare(it.weak_borrow(), end).is_lt()),
+            Bound::Unbounded => self.slice.len(),
+        };
+
+        (low, high.max(low))
+    }
+
     pub fn replace(&mut self, index: usize, value: T) -> T {
         if index >= self.len() {
             let len = self.len();
@@ -256,19 +300,19 @@ impl<T, C: Comparator<T>> IndexMut<RangeToInclusive<usize>> for SortedSlice<T, C
 
 impl<'a, T, C: Comparator<T>> IntoIterator for &'a SortedSlice<T, C> {
     type Item = &'a T;
-    type IntoIter = core::slice::Iter<'a, T>;
+    type IntoIter = SortedIter<core::slice::Iter<'a, T>, RefComparator<C>>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.slice.iter()
+        self.iter()
     }
 }
 
 impl<'a, T, C: Comparator<T>> IntoIterator for &'a mut SortedSlice<T, C> {
     type Item = &'a T;
-    type IntoIter = core::slice::Iter<'a, T>;
+    type IntoIter = SortedIter<core::slice::Iter<'a, T>, RefComparator<C>>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.slice.iter()
+        self.iter()
     }
 }
 
@@ -280,6 +324,25 @@ impl<T, C: Comparator<T>> core::iter::FromIterator<T> for Box<SortedSlice<T, C>>
     }
 }
 
+// `FromIterator` can't be implemented for `Rc<SortedSlice<T, C>>` or `Arc<SortedSlice<T, C>>`:
+// neither `Rc` nor `Arc` is fundamental, so the orphan rule rejects it (E0210)
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<T, C: Comparator<T>> SortedSlice<T, C> {
+    pub fn from_iter_rc<I: IntoIterator<Item = T>>(iter: I) -> Rc<SortedSlice<T, C>> {
+        let mut vec: alloc::vec::Vec<T> = core::iter::FromIterator::from_iter(iter);
+        vec.sort_by(C::compare);
+        from_rc_slice_unchecked(Rc::from(vec))
+    }
+
+    #[cfg(target_has_atomic = "ptr")]
+    pub fn from_iter_arc<I: IntoIterator<Item = T>>(iter: I) -> Arc<SortedSlice<T, C>> {
+        let mut vec: alloc::vec::Vec<T> = core::iter::FromIterator::from_iter(iter);
+        vec.sort_by(C::compare);
+        from_arc_slice_unchecked(Arc::from(vec))
+    }
+}
+
 pub(crate) fn from_slice_unchecked<T, C: Comparator<T>>(slice: &[T]) -> &SortedSlice<T, C> {
     unsafe { &*(slice as *const [T] as *const SortedSlice<T, C>) }
 }
@@ -298,6 +361,22 @@ pub(crate) fn from_boxed_slice_unchecked<T, C: Comparator<T>>(
     unsafe { Box::from_raw(Box::into_raw(slice) as *mut SortedSlice<T, C>) }
 }
 
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub(crate) fn from_rc_slice_unchecked<T, C: Comparator<T>>(
+    slice: Rc<[T]>,
+) -> Rc<SortedSlice<T, C>> {
+    unsafe { Rc::from_raw(Rc::into_raw(slice) as *const SortedSlice<T, C>) }
+}
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub(crate) fn from_arc_slice_unchecked<T, C: Comparator<T>>(
+    slice: Arc<[T]>,
+) -> Arc<SortedSlice<T, C>> {
+    unsafe { Arc::from_raw(Arc::into_raw(slice) as *const SortedSlice<T, C>) }
+}
+
 #[cfg(feature = "alloc")]
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
 pub fn sort_mut_slice<T, C: Comparator<T>>(slice: &mut [T]) -> &mut SortedSlice<T, C> {
@@ -316,3 +395,22 @@ pub fn sort_mut_slice_unstable<T, C: Comparator<T>>(slice: &mut [T]) -> &mut Sor
     slice.sort_unstable_by(C::compare);
     from_mut_slice_unchecked(slice)
 }
+
+#[cfg(all(test, feature = "alloc"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_from_iter_shared() {
+        let rc: Rc<SortedSlice<u32>> = SortedSlice::from_iter_rc([5, 1, 3]);
+        assert_eq!(rc.as_slice(), &[1, 3, 5]);
+        assert_eq!(rc.find(&3), Some(&3));
+
+        #[cfg(target_has_atomic = "ptr")]
+        {
+            let arc: Arc<SortedSlice<u32>> = SortedSlice::from_iter_arc([5, 1, 3]);
+            assert_eq!(arc.as_slice(), &[1, 3, 5]);
+            assert_eq!(arc.find(&3), Some(&3));
+        }
+    }
+}
@@ -1,14 +1,23 @@
-use alloc::{boxed::Box, vec::Vec};
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+use alloc::{
+    boxed::Box,
+    collections::{BTreeMap, BTreeSet, BinaryHeap},
+    rc::Rc,
+    vec::Vec,
+};
 use core::{
     borrow::{Borrow, BorrowMut},
+    cmp::Ordering,
     fmt::{self, Debug},
     iter::FromIterator,
     marker::PhantomData,
-    ops::{Deref, DerefMut, Index, IndexMut},
+    ops::{Deref, DerefMut, Index, IndexMut, RangeBounds},
 };
 
 use crate::{
-    comparator::{Comparator, OrdComparator},
+    comparator::{Comparator, OrdComparator, RefComparator},
+    iter::{self, SortedIter},
     sorted_slice::{self, SortedSlice},
     weak_borrow::WeakBorrow,
 };
@@ -28,6 +37,17 @@ impl<T, C: Comparator<T>> SortedVec<T, C> {
         }
     }
 
+    pub fn with_capacity(capacity: usize) -> Self {
+        SortedVec {
+            _comparator: PhantomData,
+            vec: Vec::with_capacity(capacity),
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.vec.capacity()
+    }
+
     pub fn as_sorted_slice(&self) -> &SortedSlice<T, C> {
         sorted_slice::from_slice_unchecked(&self.vec)
     }
@@ -36,10 +56,36 @@ impl<T, C: Comparator<T>> SortedVec<T, C> {
         sorted_slice::from_mut_slice_unchecked(&mut self.vec)
     }
 
+    pub fn find<U>(&self, item: &U) -> Option<&T>
+    where
+        T: WeakBorrow<U>,
+        C: Comparator<U>,
+    {
+        self.as_sorted_slice().find(item)
+    }
+
+    pub fn range<U, R>(&self, range: R) -> &SortedSlice<T, C>
+    where
+        T: WeakBorrow<U>,
+        C: Comparator<U>,
+        R: RangeBounds<U>,
+    {
+        self.as_sorted_slice().range(range)
+    }
+
     pub fn into_boxed_sorted_slice(self) -> Box<SortedSlice<T, C>> {
         sorted_slice::from_boxed_slice_unchecked(self.vec.into_boxed_slice())
     }
 
+    pub fn into_rc_sorted_slice(self) -> Rc<SortedSlice<T, C>> {
+        sorted_slice::from_rc_slice_unchecked(Rc::from(self.vec))
+    }
+
+    #[cfg(target_has_atomic = "ptr")]
+    pub fn into_arc_sorted_slice(self) -> Arc<SortedSlice<T, C>> {
+        sorted_slice::from_arc_slice_unchecked(Arc::from(self.vec))
+    }
+
     pub fn insert(&mut self, item: T) {
         let insert_at = self
             .vec
@@ -64,6 +110,50 @@ impl<T, C: Comparator<T>> SortedVec<T, C> {
             .ok()
             .map(|index| self.vec.remove(index))
     }
+
+    pub fn dedup(&mut self) {
+        self.vec
+            .dedup_by(|dup, kept| C::compare(kept, dup) == Ordering::Equal);
+    }
+
+    pub fn dedup_keep_last(&mut self) {
+        self.vec.dedup_by(|dup, kept| {
+            if C::compare(kept, dup) == Ordering::Equal {
+                core::mem::swap(kept, dup);
+                true
+            } else {
+                false
+            }
+        });
+    }
+
+    pub fn dedup_merge<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T, &mut T),
+    {
+        self.vec.dedup_by(|dup, kept| {
+            if C::compare(kept, dup) == Ordering::Equal {
+                f(kept, dup);
+                true
+            } else {
+                false
+            }
+        });
+
+        // the merge callback may have changed the order of the kept items
+        if !self
+            .vec
+            .is_sorted_by(|x, y| C::compare(x, y) != Ordering::Greater)
+        {
+            self.vec.sort_by(C::compare);
+        }
+    }
+}
+
+impl<T: Ord> SortedVec<T> {
+    pub fn from_btree_map_keys<V>(map: BTreeMap<T, V>) -> Self {
+        from_vec_unchecked(map.into_keys().collect())
+    }
 }
 
 impl<T: Clone, C: Comparator<T>> Clone for SortedVec<T, C> {
@@ -101,6 +191,43 @@ impl<T, C: Comparator<T>> DerefMut for SortedVec<T, C> {
     }
 }
 
+impl<T, C: Comparator<T>> From<SortedVec<T, C>> for Box<SortedSlice<T, C>> {
+    fn from(vec: SortedVec<T, C>) -> Box<SortedSlice<T, C>> {
+        vec.into_boxed_sorted_slice()
+    }
+}
+
+impl<T, C: Comparator<T>> From<SortedVec<T, C>> for Rc<SortedSlice<T, C>> {
+    fn from(vec: SortedVec<T, C>) -> Rc<SortedSlice<T, C>> {
+        vec.into_rc_sorted_slice()
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<T, C: Comparator<T>> From<SortedVec<T, C>> for Arc<SortedSlice<T, C>> {
+    fn from(vec: SortedVec<T, C>) -> Arc<SortedSlice<T, C>> {
+        vec.into_arc_sorted_slice()
+    }
+}
+
+impl<T: Ord> From<BTreeSet<T>> for SortedVec<T> {
+    fn from(set: BTreeSet<T>) -> SortedVec<T> {
+        from_vec_unchecked(set.into_iter().collect())
+    }
+}
+
+impl<T: Ord> From<BinaryHeap<T>> for SortedVec<T> {
+    fn from(heap: BinaryHeap<T>) -> SortedVec<T> {
+        from_vec_unchecked(heap.into_sorted_vec())
+    }
+}
+
+impl<T: Ord, C: Comparator<T>> From<SortedVec<T, C>> for BTreeSet<T> {
+    fn from(vec: SortedVec<T, C>) -> BTreeSet<T> {
+        vec.into_iter().collect()
+    }
+}
+
 impl<T, C: Comparator<T>> Borrow<[T]> for SortedVec<T, C> {
     fn borrow(&self) -> &[T] {
         self.as_slice()
@@ -159,28 +286,28 @@ where
 
 impl<T, C: Comparator<T>> IntoIterator for SortedVec<T, C> {
     type Item = T;
-    type IntoIter = alloc::vec::IntoIter<T>;
+    type IntoIter = SortedIter<alloc::vec::IntoIter<T>, C>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.vec.into_iter()
+        iter::from_sorted_iter_unchecked(self.vec.into_iter())
     }
 }
 
 impl<'a, T, C: Comparator<T>> IntoIterator for &'a SortedVec<T, C> {
     type Item = &'a T;
-    type IntoIter = core::slice::Iter<'a, T>;
+    type IntoIter = SortedIter<core::slice::Iter<'a, T>, RefComparator<C>>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.vec.iter()
+        self.as_sorted_slice().iter()
     }
 }
 
 impl<'a, T, C: Comparator<T>> IntoIterator for &'a mut SortedVec<T, C> {
     type Item = &'a T;
-    type IntoIter = core::slice::Iter<'a, T>;
+    type IntoIter = SortedIter<core::slice::Iter<'a, T>, RefComparator<C>>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.vec.iter()
+        self.as_sorted_slice().iter()
     }
 }
 
@@ -204,7 +331,77 @@ pub(crate) fn from_vec_unchecked<T, C: Comparator<T>>(vec: Vec<T>) -> SortedVec<
     }
 }
 
+pub(crate) fn as_mut_vec_unchecked<T, C: Comparator<T>>(vec: &mut SortedVec<T, C>) -> &mut Vec<T> {
+    &mut vec.vec
+}
+
 pub fn sort_vec<T, C: Comparator<T>>(mut vec: Vec<T>) -> SortedVec<T, C> {
     vec.sort_by(C::compare);
     from_vec_unchecked(vec)
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct ByKey;
+
+    impl Comparator<(char, u32)> for ByKey {
+        fn compare(x: &(char, u32), y: &(char, u32)) -> Ordering {
+            Ord::cmp(&x.0, &y.0)
+        }
+    }
+
+    #[test]
+    fn test_dedup() {
+        let vec = sorted_vec![@ByKey; ('a', 1), ('b', 2), ('a', 3), ('c', 4), ('b', 5), ('a', 6)];
+
+        let mut first = vec.clone();
+        first.dedup();
+        assert_eq!(first.as_slice(), &[('a', 1), ('b', 2), ('c', 4)]);
+
+        let mut last = vec.clone();
+        last.dedup_keep_last();
+        assert_eq!(last.as_slice(), &[('a', 6), ('b', 5), ('c', 4)]);
+
+        let mut merged = vec;
+        merged.dedup_merge(|kept, dup| kept.1 += dup.1);
+        assert_eq!(merged.as_slice(), &[('a', 10), ('b', 7), ('c', 4)]);
+    }
+
+    #[test]
+    fn test_into_shared_sorted_slice() {
+        let vec = sorted_vec![@ByKey; ('b', 2), ('a', 1), ('c', 3)];
+
+        let rc: Rc<SortedSlice<_, ByKey>> = vec.clone().into();
+        assert_eq!(rc.as_slice(), &[('a', 1), ('b', 2), ('c', 3)]);
+        assert_eq!(rc.find(&('b', 0)), Some(&('b', 2)));
+        assert_eq!(rc.find(&('d', 0)), None);
+
+        #[cfg(target_has_atomic = "ptr")]
+        {
+            let arc: Arc<SortedSlice<_, ByKey>> = vec.into();
+            assert_eq!(arc.as_slice(), &[('a', 1), ('b', 2), ('c', 3)]);
+            assert_eq!(arc.find(&('b', 0)), Some(&('b', 2)));
+            assert_eq!(arc.find(&('d', 0)), None);
+        }
+    }
+
+    #[test]
+    fn test_collection_conversions() {
+        let map: BTreeMap<u32, char> = [(3, 'c'), (1, 'a'), (2, 'b')].into_iter().collect();
+        let keys = SortedVec::from_btree_map_keys(map);
+        assert_eq!(keys.as_slice(), &[1, 2, 3]);
+
+        let set: BTreeSet<u32> = [5, 1, 4, 1].into_iter().collect();
+        let vec = SortedVec::from(set);
+        assert_eq!(vec.as_slice(), &[1, 4, 5]);
+
+        let heap: BinaryHeap<u32> = [3, 9, 1, 3, 7].into_iter().collect();
+        let vec = SortedVec::from(heap);
+        assert_eq!(vec.as_slice(), &[1, 3, 3, 7, 9]);
+
+        let set = BTreeSet::from(vec);
+        assert!(set.iter().eq([1, 3, 7, 9].iter()));
+    }
+}
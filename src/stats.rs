@@ -0,0 +1,120 @@
+use core::cmp::Ordering;
+
+use crate::{comparator::Comparator, sorted_slice::SortedSlice, utils::binary_search_range};
+
+pub trait ToF64 {
+    fn to_f64(&self) -> f64;
+}
+
+macro_rules! impl_to_f64 {
+    ($($ty:ty),*) => {
+        $(
+            impl ToF64 for $ty {
+                fn to_f64(&self) -> f64 {
+                    *self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_to_f64!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QuantileMethod {
+    Nearest,
+    Lower,
+    Higher,
+    Linear,
+    Midpoint,
+}
+
+impl<T: ToF64, C: Comparator<T>> SortedSlice<T, C> {
+    pub fn median(&self) -> Option<f64> {
+        self.quantile(0.5, QuantileMethod::Linear)
+    }
+
+    pub fn quantile(&self, q: f64, method: QuantileMethod) -> Option<f64> {
+        if !(0.0..=1.0).contains(&q) {
+            panic!("quantile must be between 0 and 1, but is {q}");
+        }
+
+        let last = self.len().checked_sub(1)?;
+        let position = q * last as f64;
+        let lower = position as usize;
+        let higher = (lower + 1).min(last);
+        let fraction = position - lower as f64;
+
+        let (x, y) = (self[lower].to_f64(), self[higher].to_f64());
+        let value = match method {
+            QuantileMethod::Lower => x,
+            QuantileMethod::Higher if fraction > 0.0 => y,
+            QuantileMethod::Higher => x,
+            QuantileMethod::Nearest if fraction > 0.5 => y,
+            QuantileMethod::Nearest if fraction == 0.5 && lower % 2 == 1 => y,
+            QuantileMethod::Nearest => x,
+            QuantileMethod::Linear => x + (y - x) * fraction,
+            QuantileMethod::Midpoint if fraction > 0.0 => (x + y) / 2.0,
+            QuantileMethod::Midpoint => x,
+        };
+
+        Some(value)
+    }
+
+    pub fn percentile_rank(&self, item: &T) -> Option<f64> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let (low, high) = binary_search_range(self.as_slice(), |it| C::compare(it, item));
+        Some(100.0 * (low as f64 + (high - low) as f64 / 2.0) / self.len() as f64)
+    }
+
+    pub fn ecdf(&self, item: &T) -> Option<f64> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let count = self
+            .as_slice()
+            .partition_point(|it| C::compare(it, item) != Ordering::Greater);
+        Some(count as f64 / self.len() as f64)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_quantile() {
+        let array = sorted_array![7, 1, 3, 9, 5];
+        let cases = [
+            (QuantileMethod::Lower, 3.0),
+            (QuantileMethod::Higher, 5.0),
+            (QuantileMethod::Nearest, 3.0),
+            (QuantileMethod::Linear, 3.4),
+            (QuantileMethod::Midpoint, 4.0),
+        ];
+
+        for (method, expected) in cases {
+            let value = array.quantile(0.3, method).unwrap();
+            assert!((value - expected).abs() < 1e-9, "{method:?}: {value}");
+        }
+
+        assert_eq!(array.median(), Some(5.0));
+        assert_eq!(sorted_array![1, 2, 3, 4].median(), Some(2.5));
+        let empty: crate::SortedArray<i32, 0> = sorted_array![];
+        assert_eq!(empty.median(), None);
+    }
+
+    #[test]
+    fn test_ranks() {
+        let array = sorted_array![1, 2, 2, 3];
+
+        assert_eq!(array.ecdf(&2), Some(0.75));
+        assert_eq!(array.ecdf(&0), Some(0.0));
+        assert_eq!(array.percentile_rank(&2), Some(50.0));
+        assert_eq!(array.percentile_rank(&4), Some(100.0));
+    }
+}
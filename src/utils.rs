@@ -1,5 +1,8 @@
 use core::cmp::Ordering;
 
+#[cfg(feature = "alloc")]
+use crate::comparator::Comparator;
+
 pub fn binary_search_range<T, F>(slice: &[T], mut f: F) -> (usize, usize)
 where
     F: FnMut(&T) -> Ordering,
@@ -14,6 +17,58 @@ where
     (low, high)
 }
 
+pub fn gallop_partition_point<T, P>(slice: &[T], mut pred: P) -> usize
+where
+    P: FnMut(&T) -> bool,
+{
+    let mut bound = 1;
+    while bound <= slice.len() && pred(&slice[bound - 1]) {
+        bound *= 2;
+    }
+
+    let low = bound / 2;
+    let high = bound.min(slice.len());
+    low + slice[low..high].partition_point(pred)
+}
+
+pub fn gallop_partition_point_rev<T, P>(slice: &[T], mut pred: P) -> usize
+where
+    P: FnMut(&T) -> bool,
+{
+    let len = slice.len();
+    let mut bound = 1;
+    while bound <= len && !pred(&slice[len - bound]) {
+        bound *= 2;
+    }
+
+    let low = len.saturating_sub(bound);
+    let high = len - bound / 2;
+    low + slice[low..high].partition_point(pred)
+}
+
+#[cfg(feature = "alloc")]
+pub fn lt<T, C: Comparator<T>>(x: &T, y: &T) -> bool {
+    C::compare(x, y) == Ordering::Less
+}
+
+#[cfg(feature = "alloc")]
+pub fn min<'a, T, C: Comparator<T>>(x: &'a T, y: &'a T) -> &'a T {
+    if lt::<T, C>(y, x) {
+        y
+    } else {
+        x
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub fn max<'a, T, C: Comparator<T>>(x: &'a T, y: &'a T) -> &'a T {
+    if lt::<T, C>(x, y) {
+        y
+    } else {
+        x
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -40,4 +95,20 @@ mod test {
             );
         }
     }
+
+    #[test]
+    fn test_gallop_partition_point() {
+        let array = [1, 2, 3, 3, 3, 4, 4, 6, 7, 8, 9, 10];
+
+        for input in 0..=11 {
+            assert_eq!(
+                gallop_partition_point(&array, |it| *it < input),
+                array.partition_point(|it| *it < input)
+            );
+            assert_eq!(
+                gallop_partition_point_rev(&array, |it| *it < input),
+                array.partition_point(|it| *it < input)
+            );
+        }
+    }
 }